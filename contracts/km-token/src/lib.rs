@@ -0,0 +1,217 @@
+//! Token KM de um clube de corrida
+//!
+//! Implementação SEP-41 implantada pelo contrato do Run Club para cada
+//! clube. O administrador (o próprio Run Club) emite tokens quando um
+//! corredor registra quilômetros e os queima no resgate por USDC, ou os
+//! retira (`clawback`) de quem é removido do clube.
+
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short,
+    token::TokenInterface, Address, Env, String,
+};
+
+mod token_operation;
+mod token_query;
+mod token_validation;
+
+pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
+pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub decimal: u32,
+    pub name: String,
+    pub symbol: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    Admin,
+    Metadata,
+    TotalSupply,
+    Balance(Address),
+    Allowance(AllowanceDataKey),
+}
+
+/// Erros retornados pelo token KM
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    NotInitialized = 1,
+    AlreadyInitialized = 2,
+    NegativeAmount = 3,
+    InsufficientBalance = 4,
+    InsufficientAllowance = 5,
+    InvalidExpiration = 6,
+    InvalidDecimal = 7,
+}
+
+#[contract]
+pub struct KmToken;
+
+#[contractimpl]
+impl KmToken {
+    /// Inicializa o token (chamado pelo Run Club logo após o deploy)
+    pub fn initialize(env: Env, admin: Address, decimal: u32, name: String, symbol: String) {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(&env, TokenError::AlreadyInitialized);
+        }
+        if decimal > 18 {
+            panic_with_error!(&env, TokenError::InvalidDecimal);
+        }
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(
+            &DataKey::Metadata,
+            &TokenMetadata {
+                decimal,
+                name,
+                symbol,
+            },
+        );
+    }
+
+    /// Emite tokens KM para um corredor (apenas administrador)
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        token_validation::check_nonnegative_amount(&env, amount);
+        let admin = token_query::admin(&env);
+        admin.require_auth();
+        token_query::bump_instance(&env);
+
+        token_operation::mint(&env, &to, amount);
+        env.events()
+            .publish((symbol_short!("mint"), admin, to), amount);
+    }
+
+    /// Retira tokens KM de uma conta (apenas administrador), como os KM perdidos por
+    /// um membro removido do clube
+    pub fn clawback(env: Env, from: Address, amount: i128) {
+        token_validation::check_nonnegative_amount(&env, amount);
+        let admin = token_query::admin(&env);
+        admin.require_auth();
+        token_query::bump_instance(&env);
+
+        token_operation::burn(&env, &from, amount);
+        env.events()
+            .publish((symbol_short!("clawback"), admin, from), amount);
+    }
+
+    /// Troca o administrador do token
+    pub fn set_admin(env: Env, new_admin: Address) {
+        let admin = token_query::admin(&env);
+        admin.require_auth();
+        token_query::bump_instance(&env);
+
+        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.events()
+            .publish((symbol_short!("set_admin"), admin), new_admin);
+    }
+
+    /// Administrador atual do token
+    pub fn admin(env: Env) -> Address {
+        token_query::admin(&env)
+    }
+
+    /// Total de tokens KM em circulação
+    pub fn total_supply(env: Env) -> i128 {
+        token_query::total_supply(&env)
+    }
+}
+
+#[contractimpl]
+impl TokenInterface for KmToken {
+    fn allowance(env: Env, from: Address, spender: Address) -> i128 {
+        token_query::bump_instance(&env);
+        token_query::allowance(&env, &from, &spender).amount
+    }
+
+    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+        from.require_auth();
+        token_validation::check_nonnegative_amount(&env, amount);
+        token_query::bump_instance(&env);
+
+        token_operation::write_allowance(&env, &from, &spender, amount, expiration_ledger);
+        env.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+    }
+
+    fn balance(env: Env, id: Address) -> i128 {
+        token_query::bump_instance(&env);
+        token_query::balance(&env, &id)
+    }
+
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        token_validation::check_nonnegative_amount(&env, amount);
+        token_query::bump_instance(&env);
+
+        token_operation::transfer(&env, &from, &to, amount);
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+        spender.require_auth();
+        token_validation::check_nonnegative_amount(&env, amount);
+        token_query::bump_instance(&env);
+
+        token_operation::spend_allowance(&env, &from, &spender, amount);
+        token_operation::transfer(&env, &from, &to, amount);
+        env.events()
+            .publish((symbol_short!("transfer"), from, to), amount);
+    }
+
+    fn burn(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        token_validation::check_nonnegative_amount(&env, amount);
+        token_query::bump_instance(&env);
+
+        token_operation::burn(&env, &from, amount);
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+        spender.require_auth();
+        token_validation::check_nonnegative_amount(&env, amount);
+        token_query::bump_instance(&env);
+
+        token_operation::spend_allowance(&env, &from, &spender, amount);
+        token_operation::burn(&env, &from, amount);
+        env.events().publish((symbol_short!("burn"), from), amount);
+    }
+
+    fn decimals(env: Env) -> u32 {
+        token_query::metadata(&env).decimal
+    }
+
+    fn name(env: Env) -> String {
+        token_query::metadata(&env).name
+    }
+
+    fn symbol(env: Env) -> String {
+        token_query::metadata(&env).symbol
+    }
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::token_query;
+use crate::token_validation;
+use crate::{
+    AllowanceDataKey, AllowanceValue, DataKey, TokenError, BALANCE_BUMP_AMOUNT,
+    BALANCE_LIFETIME_THRESHOLD,
+};
+
+fn write_balance(env: &Env, id: &Address, balance: i128) {
+    let key = DataKey::Balance(id.clone());
+    env.storage().persistent().set(&key, &balance);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn write_total_supply(env: &Env, total_supply: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalSupply, &total_supply);
+}
+
+pub fn mint(env: &Env, to: &Address, amount: i128) {
+    let balance = token_query::balance(env, to);
+    write_balance(env, to, balance + amount);
+    write_total_supply(env, token_query::total_supply(env) + amount);
+}
+
+pub fn burn(env: &Env, from: &Address, amount: i128) {
+    let balance = token_validation::check_sufficient_balance(env, from, amount);
+    write_balance(env, from, balance - amount);
+    write_total_supply(env, token_query::total_supply(env) - amount);
+}
+
+pub fn transfer(env: &Env, from: &Address, to: &Address, amount: i128) {
+    let from_balance = token_validation::check_sufficient_balance(env, from, amount);
+    write_balance(env, from, from_balance - amount);
+    let to_balance = token_query::balance(env, to);
+    write_balance(env, to, to_balance + amount);
+}
+
+pub fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) {
+    if amount > 0 && expiration_ledger < env.ledger().sequence() {
+        panic_with_error!(env, TokenError::InvalidExpiration);
+    }
+
+    let key = DataKey::Allowance(AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    });
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    env.storage().temporary().set(&key, &allowance);
+
+    if amount > 0 {
+        let live_for = expiration_ledger - env.ledger().sequence();
+        env.storage()
+            .temporary()
+            .extend_ttl(&key, live_for, live_for);
+    }
+}
+
+pub fn spend_allowance(env: &Env, from: &Address, spender: &Address, amount: i128) {
+    let allowance = token_query::allowance(env, from, spender);
+    if allowance.amount < amount {
+        panic_with_error!(env, TokenError::InsufficientAllowance);
+    }
+    if amount > 0 {
+        write_allowance(
+            env,
+            from,
+            spender,
+            allowance.amount - amount,
+            allowance.expiration_ledger,
+        );
+    }
+}
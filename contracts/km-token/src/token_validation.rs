@@ -0,0 +1,19 @@
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::token_query;
+use crate::TokenError;
+
+pub fn check_nonnegative_amount(env: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(env, TokenError::NegativeAmount);
+    }
+}
+
+/// Retorna o saldo atual se ele cobrir `amount`
+pub fn check_sufficient_balance(env: &Env, account: &Address, amount: i128) -> i128 {
+    let balance = token_query::balance(env, account);
+    if balance < amount {
+        panic_with_error!(env, TokenError::InsufficientBalance);
+    }
+    balance
+}
@@ -0,0 +1,175 @@
+//! Testes do token KM (SEP-41)
+//!
+//! Objetivo: Verificar que o token KM de um clube se comporta como um token SEP-41
+//! completo: metadados, emissão pelo administrador, transferências, allowances e queima,
+//! além do `clawback` do administrador.
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    Address, Env, String,
+};
+
+use km_token::{KmToken, KmTokenClient, TokenError};
+
+fn create_token<'a>(env: &Env, admin: &Address) -> KmTokenClient<'a> {
+    let token_id = env.register(KmToken, ());
+    let token = KmTokenClient::new(env, &token_id);
+    token.initialize(
+        admin,
+        &7u32,
+        &String::from_str(env, "Clube Alpha"),
+        &String::from_str(env, "KM"),
+    );
+    token
+}
+
+#[test]
+fn test_metadata_and_mint() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let runner = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    let sep41 = TokenClient::new(&env, &token.address);
+
+    assert_eq!(sep41.decimals(), 7u32);
+    assert_eq!(sep41.name(), String::from_str(&env, "Clube Alpha"));
+    assert_eq!(sep41.symbol(), String::from_str(&env, "KM"));
+    assert_eq!(token.admin(), admin);
+
+    token.mint(&runner, &50i128);
+    assert_eq!(sep41.balance(&runner), 50i128);
+    assert_eq!(token.total_supply(), 50i128);
+}
+
+#[test]
+fn test_transfer_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let runner_a = Address::generate(&env);
+    let runner_b = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    let sep41 = TokenClient::new(&env, &token.address);
+
+    token.mint(&runner_a, &100i128);
+    sep41.transfer(&runner_a, &runner_b, &30i128);
+    assert_eq!(sep41.balance(&runner_a), 70i128);
+    assert_eq!(sep41.balance(&runner_b), 30i128);
+
+    sep41.burn(&runner_b, &10i128);
+    assert_eq!(sep41.balance(&runner_b), 20i128);
+    assert_eq!(token.total_supply(), 90i128);
+
+    assert_eq!(
+        sep41.try_transfer(&runner_b, &runner_a, &21i128),
+        Err(Ok(TokenError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_allowance_transfer_from_and_burn_from() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    let sep41 = TokenClient::new(&env, &token.address);
+
+    token.mint(&owner, &100i128);
+    let expiration = env.ledger().sequence() + 100;
+    sep41.approve(&owner, &spender, &40i128, &expiration);
+    assert_eq!(sep41.allowance(&owner, &spender), 40i128);
+
+    sep41.transfer_from(&spender, &owner, &receiver, &25i128);
+    assert_eq!(sep41.allowance(&owner, &spender), 15i128);
+    assert_eq!(sep41.balance(&receiver), 25i128);
+
+    sep41.burn_from(&spender, &owner, &15i128);
+    assert_eq!(sep41.allowance(&owner, &spender), 0i128);
+    assert_eq!(sep41.balance(&owner), 60i128);
+
+    assert_eq!(
+        sep41.try_transfer_from(&spender, &owner, &receiver, &1i128),
+        Err(Ok(TokenError::InsufficientAllowance.into()))
+    );
+
+    // Allowances expiram após o ledger informado
+    sep41.approve(&owner, &spender, &10i128, &expiration);
+    env.ledger()
+        .with_mut(|li| li.sequence_number = expiration + 1);
+    assert_eq!(sep41.allowance(&owner, &spender), 0i128);
+}
+
+#[test]
+fn test_initialize_only_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    let result = token.try_initialize(
+        &admin,
+        &7u32,
+        &String::from_str(&env, "Outro"),
+        &String::from_str(&env, "KM"),
+    );
+    assert_eq!(result, Err(Ok(TokenError::AlreadyInitialized.into())));
+}
+
+#[test]
+fn test_only_admin_can_mint() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let runner = Address::generate(&env);
+    let token = create_token(&env, &admin);
+
+    // Sem a assinatura do administrador a emissão falha
+    assert!(token.try_mint(&runner, &10i128).is_err());
+    assert_eq!(token.total_supply(), 0i128);
+}
+
+#[test]
+fn test_admin_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let runner = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    let sep41 = TokenClient::new(&env, &token.address);
+
+    token.mint(&runner, &50i128);
+    token.clawback(&runner, &20i128);
+    assert_eq!(sep41.balance(&runner), 30i128);
+    assert_eq!(token.total_supply(), 30i128);
+
+    assert_eq!(
+        token.try_clawback(&runner, &31i128),
+        Err(Ok(TokenError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_only_admin_can_clawback() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let runner = Address::generate(&env);
+    let token = create_token(&env, &admin);
+    token.mock_all_auths().mint(&runner, &10i128);
+
+    // Sem a assinatura do administrador o clawback falha
+    assert!(token.try_clawback(&runner, &10i128).is_err());
+    assert_eq!(token.total_supply(), 10i128);
+}
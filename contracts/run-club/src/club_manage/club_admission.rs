@@ -0,0 +1,207 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Bytes, BytesN, Env, Vec};
+
+use super::club_queue::ClubQueue;
+use crate::ttl;
+use crate::{
+    ClubPermission, DataKey, MembershipPolicy, RunClubContract, RunClubContractArgs,
+    RunClubContractClient, RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Define como novos membros entram no clube (organizador ou co-organizador
+    /// com `Membership`)
+    ///
+    /// Pedidos pendentes e convites continuam valendo se a política mudar.
+    pub fn set_membership_policy(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        policy: MembershipPolicy,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        club.membership_policy = policy.clone();
+        Self::save_club(&env, &mut club);
+
+        env.events()
+            .publish((symbol_short!("mem_pol"), club_id), policy);
+
+        Ok(())
+    }
+
+    /// Pede para entrar em um clube com a política `ApprovalRequired`
+    ///
+    /// Um segundo pedido do mesmo endereço retorna `MemberAlreadyExists`.
+    pub fn request_to_join(env: Env, club_id: u64, member: Address) -> Result<(), RunClubError> {
+        member.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        if club.membership_policy != MembershipPolicy::ApprovalRequired {
+            return Err(RunClubError::MembershipRestricted);
+        }
+        if env.ledger().timestamp() > club.registration_close_timestamp {
+            return Err(RunClubError::RegistrationClosed);
+        }
+        if Self::member(&env, club_id, &member).is_some() {
+            return Err(RunClubError::MemberAlreadyExists);
+        }
+
+        if ClubQueue::JoinRequests.contains(&env, club_id, &member) {
+            return Err(RunClubError::MemberAlreadyExists);
+        }
+        ClubQueue::JoinRequests.push(&env, club_id, &member);
+
+        env.events()
+            .publish((symbol_short!("join_req"), club_id), member);
+
+        Ok(())
+    }
+
+    /// Aprova um pedido de entrada (organizador ou co-organizador com `Membership`)
+    pub fn approve_member(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        member: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        take_join_request(&env, club_id, &member)?;
+        Self::admit_member(&env, &mut club, &member)?;
+
+        env.events()
+            .publish((symbol_short!("join_ok"), club_id), member);
+
+        Ok(())
+    }
+
+    /// Recusa um pedido de entrada (organizador ou co-organizador com `Membership`)
+    pub fn reject_member(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        member: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        take_join_request(&env, club_id, &member)?;
+
+        env.events()
+            .publish((symbol_short!("join_no"), club_id), member);
+
+        Ok(())
+    }
+
+    /// Pedidos de entrada pendentes, na ordem em que chegaram
+    ///
+    /// `cursor` conta posições da fila, e as deixadas por pedidos já decididos ficam
+    /// de fora, então a página pode vir com menos de `limit` endereços.
+    pub fn get_join_requests(
+        env: Env,
+        club_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(ClubQueue::JoinRequests.page(&env, club_id, cursor, limit))
+    }
+
+    /// Registra um convite pelo sha256 do código (organizador ou co-organizador
+    /// com `Membership`)
+    ///
+    /// O código em si nunca fica no contrato; quem o recebe revela o código para entrar.
+    /// O convite vive até o fim do período mais a janela de resgate, como o `bump_club`.
+    pub fn add_invite(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        code_hash: BytesN<32>,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        let key = DataKey::Invite(club_id, code_hash.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(RunClubError::InvalidInvite);
+        }
+        env.storage().persistent().set(&key, &true);
+        ttl::bump_until(&env, &key, ttl::ledgers_until_expiry(&env, &club));
+
+        env.events()
+            .publish((symbol_short!("inv_add"), club_id), code_hash);
+
+        Ok(())
+    }
+
+    /// Cancela um convite ainda não usado (organizador ou co-organizador com `Membership`)
+    pub fn revoke_invite(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        code_hash: BytesN<32>,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        let key = DataKey::Invite(club_id, code_hash.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(RunClubError::InvalidInvite);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("inv_del"), club_id), code_hash);
+
+        Ok(())
+    }
+
+    /// Entra no clube revelando o código de um convite, que vale uma única vez
+    ///
+    /// O convite é aceito qualquer que seja a política do clube.
+    pub fn join_with_invite(
+        env: Env,
+        club_id: u64,
+        member: Address,
+        code: Bytes,
+    ) -> Result<(), RunClubError> {
+        member.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+
+        let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+        let key = DataKey::Invite(club_id, code_hash.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(RunClubError::InvalidInvite);
+        }
+
+        Self::admit_member(&env, &mut club, &member)?;
+        env.storage().persistent().remove(&key);
+
+        env.events()
+            .publish((symbol_short!("inv_use"), club_id), (member, code_hash));
+
+        Ok(())
+    }
+}
+
+/// Tira o pedido do endereço da fila de pedidos pendentes
+fn take_join_request(env: &Env, club_id: u64, member: &Address) -> Result<(), RunClubError> {
+    if !ClubQueue::JoinRequests.remove(env, club_id, member) {
+        return Err(RunClubError::MemberNotFound);
+    }
+    Ok(())
+}
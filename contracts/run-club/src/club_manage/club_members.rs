@@ -0,0 +1,247 @@
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::token_factory;
+use crate::ttl;
+use crate::{
+    Club, ClubSummary, DataKey, Member, MemberRole, RunClubContract, RunClubError, UserClub,
+    MAX_PAGE_LIMIT,
+};
+
+impl RunClubContract {
+    /// Lê o registro de um membro do clube
+    pub(crate) fn member(env: &Env, club_id: u64, user: &Address) -> Option<Member> {
+        let key = DataKey::Member(club_id, user.clone());
+        let member = env.storage().persistent().get(&key);
+        ttl::bump(env, &key);
+        member
+    }
+
+    /// Inscreve um endereço no clube, se as inscrições estiverem abertas, ou o põe na
+    /// fila de espera se o clube estiver cheio
+    ///
+    /// Usado por todos os caminhos de entrada, depois de conferida a política do clube.
+    pub(crate) fn admit_member(
+        env: &Env,
+        club: &mut Club,
+        user: &Address,
+    ) -> Result<(), RunClubError> {
+        if env.ledger().timestamp() > club.registration_close_timestamp {
+            return Err(RunClubError::RegistrationClosed);
+        }
+        if Self::member(env, club.id, user).is_some() {
+            return Err(RunClubError::MemberAlreadyExists);
+        }
+        if Self::is_full(club) {
+            return Self::enqueue_waitlist(env, club.id, user);
+        }
+
+        let role = if *user == club.organizer {
+            MemberRole::Organizer
+        } else {
+            MemberRole::Member
+        };
+        Self::insert_member(env, club, user, role, env.ledger().timestamp());
+        Self::save_club(env, club);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("mem_add"),),
+            (club.id, user.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Grava um novo membro no fim da lista do clube e incrementa o contador
+    pub(crate) fn insert_member(
+        env: &Env,
+        club: &mut Club,
+        user: &Address,
+        role: MemberRole,
+        joined_at: u64,
+    ) {
+        let index = club.member_count;
+        let member = Member {
+            joined_at,
+            role,
+            index,
+        };
+
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Member(club.id, user.clone()), &member);
+        storage.set(&DataKey::MemberAt(club.id, index), user);
+        ttl::bump(env, &DataKey::Member(club.id, user.clone()));
+        ttl::bump(env, &DataKey::MemberAt(club.id, index));
+        club.member_count += 1;
+
+        // KM guardados de quem volta na mesma temporada voltam a contar
+        let km = Self::user_km(env, user, club);
+        Self::update_km_totals(env, club, 0, km);
+        Self::link_user_club(env, user, club.id, MemberRole::Member);
+    }
+
+    /// Apaga um membro, movendo o último da lista para a posição liberada
+    pub(crate) fn delete_member(env: &Env, club: &mut Club, user: &Address, member: &Member) {
+        let storage = env.storage().persistent();
+        let last_index = club.member_count - 1;
+
+        if member.index != last_index {
+            let last: Address = storage
+                .get(&DataKey::MemberAt(club.id, last_index))
+                .unwrap();
+            let mut moved: Member = storage
+                .get(&DataKey::Member(club.id, last.clone()))
+                .unwrap();
+            moved.index = member.index;
+            storage.set(&DataKey::MemberAt(club.id, member.index), &last);
+            storage.set(&DataKey::Member(club.id, last), &moved);
+        }
+
+        storage.remove(&DataKey::MemberAt(club.id, last_index));
+        storage.remove(&DataKey::Member(club.id, user.clone()));
+        club.member_count -= 1;
+
+        let km = Self::user_km(env, user, club);
+        Self::update_km_totals(env, club, km, 0);
+        Self::unlink_user_club(env, user, club.id, MemberRole::Member);
+    }
+
+    /// Zera os KM da temporada atual de um membro removido e retira os tokens KM
+    /// correspondentes
+    ///
+    /// Os tokens KM são transferíveis: só sai o que ainda está na conta do membro.
+    /// Depois do fechamento os KM da temporada já viraram um valor fixado, que
+    /// continua sendo do membro e pode ser resgatado por ele.
+    pub(crate) fn forfeit_km(env: &Env, club: &Club, user: &Address) {
+        if Self::settlement(env, club).is_some() {
+            return;
+        }
+
+        let key = DataKey::UserKmTokens(user.clone(), club.id, club.season);
+        let km: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().remove(&key);
+
+        let held = token::Client::new(env, &club.token_address).balance(user);
+        let amount = km.min(held);
+        if amount > 0 {
+            token_factory::TokenClient::new(env, &club.token_address).clawback(user, &amount);
+        }
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("km_lost"),),
+            (club.id, user.clone(), km),
+        );
+    }
+
+    /// Apaga até `limit` membros de um clube removido, do fim da lista para o início
+    pub(crate) fn purge_members(env: &Env, club: &mut Club, limit: u32) {
+        let storage = env.storage().persistent();
+        let end = club.member_count.saturating_sub(limit);
+        for index in (end..club.member_count).rev() {
+            let user: Address = storage.get(&DataKey::MemberAt(club.id, index)).unwrap();
+            storage.remove(&DataKey::MemberAt(club.id, index));
+            storage.remove(&DataKey::Member(club.id, user.clone()));
+            Self::unlink_user_club(env, &user, club.id, MemberRole::Member);
+        }
+        club.member_count = end;
+    }
+
+    /// Marca o papel do usuário no índice `UserClubs`, criando a entrada se preciso
+    pub(crate) fn link_user_club(env: &Env, user: &Address, club_id: u64, role: MemberRole) {
+        let mut clubs = Self::user_club_entries(env, user);
+        let position = clubs.iter().position(|entry| entry.club_id == club_id);
+        let mut entry = match position {
+            Some(i) => clubs.get(i as u32).unwrap(),
+            None => UserClub {
+                club_id,
+                is_member: false,
+                is_organizer: false,
+            },
+        };
+
+        match role {
+            MemberRole::Member => entry.is_member = true,
+            MemberRole::Organizer => entry.is_organizer = true,
+        }
+
+        match position {
+            Some(i) => clubs.set(i as u32, entry),
+            None => clubs.push_back(entry),
+        }
+        Self::write_user_club_entries(env, user, &clubs);
+    }
+
+    /// Desmarca o papel do usuário no índice `UserClubs`, apagando a entrada sem papéis
+    pub(crate) fn unlink_user_club(env: &Env, user: &Address, club_id: u64, role: MemberRole) {
+        let mut clubs = Self::user_club_entries(env, user);
+        let Some(i) = clubs.iter().position(|entry| entry.club_id == club_id) else {
+            return;
+        };
+        let i = i as u32;
+        let mut entry = clubs.get(i).unwrap();
+
+        match role {
+            MemberRole::Member => entry.is_member = false,
+            MemberRole::Organizer => entry.is_organizer = false,
+        }
+
+        if entry.is_member || entry.is_organizer {
+            clubs.set(i, entry);
+        } else {
+            clubs.remove(i);
+        }
+        Self::write_user_club_entries(env, user, &clubs);
+    }
+
+    pub(crate) fn user_club_entries(env: &Env, user: &Address) -> Vec<UserClub> {
+        let key = DataKey::UserClubs(user.clone());
+        let clubs = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ttl::bump(env, &key);
+        clubs
+    }
+
+    fn write_user_club_entries(env: &Env, user: &Address, clubs: &Vec<UserClub>) {
+        let key = DataKey::UserClubs(user.clone());
+        if clubs.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, clubs);
+            ttl::bump(env, &key);
+        }
+    }
+
+    /// Resumo de um clube para as listagens
+    pub(crate) fn club_summary(club: &Club) -> ClubSummary {
+        ClubSummary {
+            id: club.id,
+            name: club.name.clone(),
+            organizer: club.organizer.clone(),
+            member_count: club.member_count,
+            usdc_deposited: club.usdc_deposited,
+            month_end_timestamp: club.month_end_timestamp,
+            is_active: club.is_active,
+            status: club.status.clone(),
+        }
+    }
+
+    /// Membros a partir da posição `cursor`, no máximo `limit` (limitado a `MAX_PAGE_LIMIT`)
+    pub(crate) fn member_page(env: &Env, club: &Club, cursor: u32, limit: u32) -> Vec<Address> {
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(club.member_count);
+
+        let mut members = Vec::new(env);
+        for index in cursor..end {
+            let member: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::MemberAt(club.id, index))
+                .unwrap();
+            members.push_back(member);
+        }
+        members
+    }
+}
@@ -1,28 +1,51 @@
-use soroban_sdk::{contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contractimpl, token, Address, BytesN, Env, IntoVal, String};
 
 use crate::token_factory;
-use crate::{Club, DataKey, RunClubContract, WithdrawalRule};
+use crate::ttl;
+use crate::{
+    Club, ClubConfig, ClubPermission, ClubStatus, DataKey, MemberRole, MembershipPolicy,
+    RemovedKmPolicy, RunAttestation, RunClubContract, RunClubContractArgs, RunClubContractClient,
+    RunClubError, Season, MAX_PAGE_LIMIT,
+};
 
+#[contractimpl]
 impl RunClubContract {
-    /// Cria um novo clube de corrida
-    pub fn create_club_(
+    /// Cria um novo clube de corrida com a configuração `config`
+    pub fn create_club(
         env: Env,
         organizer: Address,
         name: String,
-        usdc_per_km: i128,
-        withdrawal_rule: WithdrawalRule,
-        duration_days: u32,
-    ) -> u64 {
+        config: ClubConfig,
+    ) -> Result<u64, RunClubError> {
         organizer.require_auth();
 
+        let ClubConfig {
+            usdc_per_km,
+            withdrawal_rule,
+            surplus_rule,
+            start_timestamp,
+            registration_close_timestamp,
+            end_timestamp,
+            limits,
+            max_members,
+        } = config;
+
         if usdc_per_km <= 0 {
-            panic!("USDC per KM must be positive");
+            return Err(RunClubError::InvalidUsdcPerKm);
         }
-
-        if duration_days == 0 {
-            panic!("Duration must be greater than 0");
+        if max_members == Some(0) {
+            return Err(RunClubError::InvalidCapacity);
         }
 
+        Self::validate_schedule(
+            &env,
+            start_timestamp,
+            registration_close_timestamp,
+            end_timestamp,
+        )?;
+        Self::validate_withdrawal_rule(&withdrawal_rule)?;
+        Self::validate_run_limits(&limits)?;
+
         // Obter próximo ID do clube
         let club_counter = env
             .storage()
@@ -32,34 +55,54 @@ impl RunClubContract {
         let club_id = club_counter + 1;
 
         // Create club token
-        let token_name = &name;
-        let token_symbol = &name;
-        let token_address =
-            token_factory::create_token(&env, &organizer, &token_name, &token_symbol);
-        // Calcular timestamp de fim do mês
+        let token_symbol = String::from_str(&env, "KM");
+        let token_address = token_factory::create_token(&env, club_id, &name, &token_symbol)?;
+
         let current_timestamp = env.ledger().timestamp();
-        let month_end_timestamp = current_timestamp + (duration_days as u64 * 24 * 60 * 60);
 
-        let club = Club {
+        let mut club = Club {
             id: club_id,
             name,
             organizer: organizer.clone(),
-            members: Vec::new(&env),
+            pending_organizer: None,
+            member_count: 0,
+            max_members,
             usdc_deposited: 0,
             usdc_per_km,
             withdrawal_rule,
-            month_end_timestamp,
+            surplus_rule,
+            removed_km_policy: RemovedKmPolicy::Forfeit,
+            membership_policy: MembershipPolicy::Open,
+            start_timestamp,
+            registration_close_timestamp,
+            month_end_timestamp: end_timestamp,
+            season: 1,
+            claim_window_secs: ttl::REDEMPTION_GRACE_PERIOD,
             is_active: false, // Será ativado quando USDC for depositado
             token_address,
+            created_at: current_timestamp,
+            status: ClubStatus::Draft,
+            limits,
         };
+        Self::register_club(&env, &mut club);
 
         // Salvar clube
-        env.storage()
-            .persistent()
-            .set(&DataKey::Club(club_id), &club);
+        Self::save_club(&env, &mut club);
+        Self::write_season(
+            &env,
+            club_id,
+            &Season {
+                season: 1,
+                start_timestamp,
+                end_timestamp,
+                rolled_over: 0,
+            },
+        );
         env.storage()
             .persistent()
             .set(&DataKey::ClubCounter, &club_id);
+        ttl::bump(&env, &DataKey::ClubCounter);
+        Self::link_user_club(&env, &organizer, club_id, MemberRole::Organizer);
 
         // Emitir evento
         env.events().publish(
@@ -67,97 +110,218 @@ impl RunClubContract {
             (club_id, organizer),
         );
 
-        club_id
+        Ok(club_id)
     }
 
-    /// Add a new member to the club
-    pub fn add_member(env: Env, club_id: u64, member: Address) {
+    /// Add a new member to the club, while its registration is open (or put them on the
+    /// waitlist when the club is full)
+    pub fn add_member(env: Env, club_id: u64, member: Address) -> Result<(), RunClubError> {
         member.require_auth();
 
-        let mut club: Club = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Club(club_id))
-            .expect("Club not found");
+        let mut club = Self::load_club(&env, club_id)?;
 
-        // Check if member already exists
-        for existing_member in club.members.iter() {
-            if existing_member == member {
-                panic!("Member already exists in club");
-            }
+        if club.membership_policy != MembershipPolicy::Open {
+            return Err(RunClubError::MembershipRestricted);
         }
 
-        club.members.push_back(member.clone());
-        env.storage()
-            .persistent()
-            .set(&DataKey::Club(club_id), &club);
+        Self::admit_member(&env, &mut club, &member)
+    }
+
+    /// Remove um membro do clube (organizador ou co-organizador com `Membership`)
+    ///
+    /// Os KM do membro seguem a `RemovedKmPolicy` do clube, e o primeiro da fila de
+    /// espera fica com a vaga.
+    pub fn remove_member(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        member: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
 
-        // Emit event
-        env.events()
-            .publish((soroban_sdk::symbol_short!("mem_add"),), (club_id, member));
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
+
+        // Encontrar e remover o membro
+        let entry = Self::member(&env, club_id, &member).ok_or(RunClubError::MemberNotFound)?;
+        Self::release_member(&env, &mut club, &member, &entry)?;
+        Self::save_club(&env, &mut club);
+
+        Ok(())
     }
 
-    /// Remove um membro do clube (apenas organizador)
-    pub fn remove_member(env: Env, club_id: u64, organizer: Address, member: Address) {
+    /// Define o que acontece com os KM de quem for removido do clube (organizador ou
+    /// co-organizador com `Membership`)
+    pub fn set_removed_km_policy(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        policy: RemovedKmPolicy,
+    ) -> Result<(), RunClubError> {
         organizer.require_auth();
 
-        let mut club: Club = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Club(club_id))
-            .expect("Club not found");
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Membership)?;
 
-        if club.organizer != organizer {
-            panic!("Only organizer can remove members");
-        }
+        club.removed_km_policy = policy.clone();
+        Self::save_club(&env, &mut club);
 
-        // Encontrar e remover o membro
-        let mut new_members = Vec::new(&env);
-        let mut member_found = false;
-
-        for existing_member in club.members.iter() {
-            if existing_member != member {
-                new_members.push_back(existing_member);
-            } else {
-                member_found = true;
+        env.events().publish(
+            (soroban_sdk::symbol_short!("km_policy"),),
+            (club_id, organizer, policy),
+        );
+
+        Ok(())
+    }
+
+    /// Remove a club (only organizer)
+    ///
+    /// The club leaves the registry on the first call; its members are deleted from the
+    /// end of the list, at most `limit` per call (up to `MAX_PAGE_LIMIT`). Returns how
+    /// many members are left, so call it again until it returns 0.
+    pub fn remove_club(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        limit: u32,
+    ) -> Result<u32, RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club_record(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        if club.status != ClubStatus::Removed {
+            if club.usdc_deposited > 0 {
+                return Err(RunClubError::ClubHasDeposits);
             }
+
+            // Keep the club in the removed registry, without its waitlist and organizer index
+            Self::clear_waitlist(&env, club_id);
+            Self::unlink_user_club(&env, &organizer, club_id, MemberRole::Organizer);
+            Self::set_club_status(&env, &mut club, ClubStatus::Removed);
+
+            // Emit event
+            env.events().publish(
+                (soroban_sdk::symbol_short!("club_del"),),
+                (club_id, organizer),
+            );
+        } else if club.member_count == 0 {
+            return Err(RunClubError::ClubNotFound);
         }
 
-        if !member_found {
-            panic!("Member not found in club");
+        Self::purge_members(&env, &mut club, limit.min(MAX_PAGE_LIMIT));
+        Self::save_club(&env, &mut club);
+        Ok(club.member_count)
+    }
+
+    /// Encerra um clube cujo período terminou (qualquer um pode chamar)
+    pub fn end_club(env: Env, club_id: u64) -> Result<(), RunClubError> {
+        let mut club = Self::load_club(&env, club_id)?;
+
+        if env.ledger().timestamp() <= club.month_end_timestamp {
+            return Err(RunClubError::ClubPeriodNotEnded);
         }
 
-        club.members = new_members;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Club(club_id), &club);
+        if Self::end_club_if_expired(&env, &mut club) {
+            Self::save_club(&env, &mut club);
+
+            env.events()
+                .publish((soroban_sdk::symbol_short!("club_end"),), club_id);
+        }
+
+        Ok(())
     }
 
-    /// Remove a club (only organizer)
-    pub fn remove_club(env: Env, club_id: u64, organizer: Address) {
+    /// Ativa um clube (organizador ou co-organizador com `Funding`)
+    pub fn activate(env: Env, club_id: u64, organizer: Address) -> Result<(), RunClubError> {
         organizer.require_auth();
 
-        let club: Club = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Club(club_id))
-            .expect("Club not found");
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Funding)?;
 
-        if club.organizer != organizer {
-            panic!("Only organizer can remove club");
+        if club.status == ClubStatus::Draft {
+            Self::set_club_status(&env, &mut club, ClubStatus::Active);
         }
+        Self::save_club(&env, &mut club);
 
-        if club.usdc_deposited > 0 {
-            panic!("Cannot remove club with deposited USDC");
+        env.events().publish(
+            (soroban_sdk::symbol_short!("club_act"),),
+            (club_id, organizer),
+        );
+
+        Ok(())
+    }
+
+    /// Deposita USDC no clube e o ativa (organizador ou co-organizador com `Funding`)
+    pub fn deposit_usdc(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        amount: i128,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        if amount <= 0 {
+            return Err(RunClubError::InvalidAmount);
         }
 
-        // Remove club from storage
-        env.storage().persistent().remove(&DataKey::Club(club_id));
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Funding)?;
+
+        // Puxar os fundos do organizador para o contrato
+        let usdc = token::Client::new(&env, &Self::usdc_token(&env)?);
+        usdc.transfer(&organizer, &env.current_contract_address(), &amount);
+
+        club.usdc_deposited += amount;
+        if club.status == ClubStatus::Draft {
+            Self::set_club_status(&env, &mut club, ClubStatus::Active);
+        }
+        Self::save_club(&env, &mut club);
 
-        // Emit event
         env.events().publish(
-            (soroban_sdk::symbol_short!("club_del"),),
-            (club_id, organizer),
+            (soroban_sdk::symbol_short!("usdc_dep"),),
+            (club_id, organizer, amount),
         );
+
+        Ok(())
+    }
+
+    /// Credita tokens KM de uma corrida atestada por um oráculo do clube
+    ///
+    /// O corredor assina a atestação exata e o oráculo a co-assina. A distância é
+    /// creditada com precisão de metro: 1 km = `KM_UNIT` unidades do token, então
+    /// 5,3 km viram 5,3 KM. Uma assinatura do oráculo inválida aborta a chamada com
+    /// o erro do host `Error(Crypto, InvalidInput)`.
+    pub fn add_km_tokens(
+        env: Env,
+        attestation: RunAttestation,
+        oracle_key: BytesN<32>,
+        signature: BytesN<64>,
+    ) -> Result<(), RunClubError> {
+        attestation
+            .user
+            .require_auth_for_args((attestation.clone(),).into_val(&env));
+
+        let (club, km_amount) = Self::prepare_run(&env, &attestation)?;
+        Self::verify_attestation(&env, &club, &attestation, &oracle_key, &signature)?;
+        Self::credit_run(&env, &club, &attestation, km_amount)
+    }
+
+    /// Credita tokens KM de uma corrida enviada por um registrador do clube
+    ///
+    /// O registrador (o serviço de backend ou o próprio organizador, por exemplo)
+    /// assina a atestação exata e dispensa a assinatura do oráculo.
+    pub fn record_km_tokens(
+        env: Env,
+        recorder: Address,
+        attestation: RunAttestation,
+    ) -> Result<(), RunClubError> {
+        recorder.require_auth_for_args((attestation.clone(),).into_val(&env));
+
+        let (club, km_amount) = Self::prepare_run(&env, &attestation)?;
+        Self::require_recorder(&env, &club, &recorder)?;
+        Self::check_attestation(&env, &club, &attestation)?;
+        Self::consume_nonce(&env, &attestation);
+        Self::credit_run(&env, &club, &attestation, km_amount)
     }
 }
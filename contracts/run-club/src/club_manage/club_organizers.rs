@@ -0,0 +1,180 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Vec};
+
+use crate::ttl;
+use crate::{
+    ClubPermission, CoOrganizer, DataKey, Member, MemberRole, RunClubContract, RunClubContractArgs,
+    RunClubContractClient, RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Propõe um novo organizador, que assume depois de aceitar (apenas organizador)
+    ///
+    /// Uma nova proposta substitui a anterior.
+    pub fn propose_organizer(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        new_organizer: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        club.pending_organizer = Some(new_organizer.clone());
+        Self::save_club(&env, &mut club);
+
+        env.events().publish(
+            (symbol_short!("org_prop"),),
+            (club_id, organizer, new_organizer),
+        );
+
+        Ok(())
+    }
+
+    /// Aceita a organização do clube proposta para este endereço
+    pub fn accept_organizer(
+        env: Env,
+        club_id: u64,
+        new_organizer: Address,
+    ) -> Result<(), RunClubError> {
+        new_organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        if club.pending_organizer != Some(new_organizer.clone()) {
+            return Err(RunClubError::NotPendingOrganizer);
+        }
+
+        let old_organizer = club.organizer.clone();
+        club.organizer = new_organizer.clone();
+        club.pending_organizer = None;
+        Self::save_club(&env, &mut club);
+
+        Self::unlink_user_club(&env, &old_organizer, club_id, MemberRole::Organizer);
+        Self::link_user_club(&env, &new_organizer, club_id, MemberRole::Organizer);
+        set_member_role(&env, club_id, &old_organizer, MemberRole::Member);
+        set_member_role(&env, club_id, &new_organizer, MemberRole::Organizer);
+
+        // O novo organizador já tem todos os escopos
+        let mut co_organizers = Self::co_organizers(&env, club_id);
+        if let Some(index) = co_organizers
+            .iter()
+            .position(|co| co.address == new_organizer)
+        {
+            co_organizers.remove(index as u32);
+            write_co_organizers(&env, club_id, &co_organizers);
+        }
+
+        env.events().publish(
+            (symbol_short!("org_new"),),
+            (club_id, old_organizer, new_organizer),
+        );
+
+        Ok(())
+    }
+
+    /// Adiciona um co-organizador ou troca os seus escopos (apenas organizador)
+    pub fn set_co_organizer(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        co_organizer: Address,
+        permissions: Vec<ClubPermission>,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        if permissions.is_empty() || co_organizer == club.organizer {
+            return Err(RunClubError::InvalidPermissions);
+        }
+
+        let entry = CoOrganizer {
+            address: co_organizer.clone(),
+            permissions: permissions.clone(),
+        };
+        let mut co_organizers = Self::co_organizers(&env, club_id);
+        match co_organizers
+            .iter()
+            .position(|co| co.address == co_organizer)
+        {
+            Some(index) => co_organizers.set(index as u32, entry),
+            None => co_organizers.push_back(entry),
+        }
+        write_co_organizers(&env, club_id, &co_organizers);
+
+        env.events().publish(
+            (symbol_short!("coorg_set"), club_id),
+            (co_organizer, permissions),
+        );
+
+        Ok(())
+    }
+
+    /// Remove um co-organizador (apenas organizador)
+    pub fn remove_co_organizer(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        co_organizer: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        let mut co_organizers = Self::co_organizers(&env, club_id);
+        let index = co_organizers
+            .iter()
+            .position(|co| co.address == co_organizer)
+            .ok_or(RunClubError::CoOrganizerNotFound)?;
+        co_organizers.remove(index as u32);
+        write_co_organizers(&env, club_id, &co_organizers);
+
+        env.events()
+            .publish((symbol_short!("coorg_del"), club_id), co_organizer);
+
+        Ok(())
+    }
+
+    /// Co-organizadores do clube e seus escopos
+    pub fn get_co_organizers(env: Env, club_id: u64) -> Result<Vec<CoOrganizer>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(Self::co_organizers(&env, club_id))
+    }
+}
+
+impl RunClubContract {
+    pub(crate) fn co_organizers(env: &Env, club_id: u64) -> Vec<CoOrganizer> {
+        let key = DataKey::CoOrganizers(club_id);
+        let co_organizers = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(env));
+        ttl::bump(env, &key);
+        co_organizers
+    }
+}
+
+fn write_co_organizers(env: &Env, club_id: u64, co_organizers: &Vec<CoOrganizer>) {
+    let key = DataKey::CoOrganizers(club_id);
+    if co_organizers.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, co_organizers);
+        ttl::bump(env, &key);
+    }
+}
+
+/// Atualiza o papel no registro de membro, se o endereço for membro do clube
+fn set_member_role(env: &Env, club_id: u64, user: &Address, role: MemberRole) {
+    let key = DataKey::Member(club_id, user.clone());
+    if let Some(mut member) = env.storage().persistent().get::<_, Member>(&key) {
+        member.role = role;
+        env.storage().persistent().set(&key, &member);
+        ttl::bump(env, &key);
+    }
+}
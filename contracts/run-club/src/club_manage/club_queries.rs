@@ -1,19 +1,132 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{contractimpl, Address, Env, Vec};
 
-use crate::{Club, DataKey, RunClubContract};
+use crate::ttl;
+use crate::{
+    Club, ClubStatus, ClubSummary, DataKey, Member, Run, RunClubContract, RunClubContractArgs,
+    RunClubContractClient, RunClubError, UserClubSummary, MAX_PAGE_LIMIT,
+};
 
+#[contractimpl]
 impl RunClubContract {
     /// Obtém informações de um clube
-    pub fn get_club(env: Env, club_id: u64) -> Club {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Club(club_id))
-            .expect("Club not found")
+    pub fn get_club(env: Env, club_id: u64) -> Result<Club, RunClubError> {
+        Self::load_club(&env, club_id)
     }
 
-    /// Get all members of a club
-    pub fn get_members(env: Env, club_id: u64) -> Vec<Address> {
-        let club = Self::get_club(env.clone(), club_id);
-        club.members
+    /// Get a page of club members, starting at position `cursor`
+    pub fn get_members(
+        env: Env,
+        club_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Ok(Self::member_page(&env, &club, cursor, limit))
     }
-}
\ No newline at end of file
+
+    /// Obtém o registro de um membro do clube
+    pub fn get_member(env: Env, club_id: u64, user: Address) -> Result<Member, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Self::member(&env, club_id, &user).ok_or(RunClubError::MemberNotFound)
+    }
+
+    /// Obtém uma página de clubes ativos
+    pub fn get_active_clubs(
+        env: Env,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<ClubSummary>, RunClubError> {
+        Ok(Self::status_page(&env, ClubStatus::Active, cursor, limit))
+    }
+
+    /// Obtém uma página de clubes em um estado do ciclo de vida
+    pub fn get_clubs_by_status(
+        env: Env,
+        status: ClubStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<ClubSummary>, RunClubError> {
+        Ok(Self::status_page(&env, status, cursor, limit))
+    }
+
+    /// Quantidade de clubes em um estado do ciclo de vida
+    pub fn get_club_count_by_status(env: Env, status: ClubStatus) -> Result<u32, RunClubError> {
+        Ok(Self::status_count(&env, status))
+    }
+
+    /// Obtém a quantidade de tokens KM de um usuário na temporada atual de um clube,
+    /// em unidades do token (`KM_UNIT` por km)
+    pub fn get_user_km_tokens(env: Env, user: Address, club_id: u64) -> Result<i128, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Ok(Self::user_km(&env, &user, &club))
+    }
+
+    /// Obtém uma página do histórico de corridas de um usuário em um clube
+    pub fn get_user_runs(
+        env: Env,
+        club_id: u64,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Run>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(Self::run_page(&env, club_id, &user, cursor, limit))
+    }
+
+    /// Quantidade de corridas de um usuário em um clube
+    pub fn get_user_run_count(env: Env, club_id: u64, user: Address) -> Result<u32, RunClubError> {
+        Ok(Self::run_count(&env, club_id, &user))
+    }
+
+    /// Total de tokens KM dos membros na temporada atual do clube
+    pub fn get_total_km_tokens(env: Env, club_id: u64) -> Result<i128, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Ok(Self::km_totals(&env, &club).total_km)
+    }
+
+    /// Verifica se o período do clube terminou
+    pub fn is_club_period_ended(env: Env, club_id: u64) -> Result<bool, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Ok(env.ledger().timestamp() > club.month_end_timestamp)
+    }
+
+    /// Get a page of the clubs a user belongs to or organizes
+    ///
+    /// Removed clubs whose members are still being deleted by `remove_club` are left
+    /// out, so a page may hold fewer than `limit` clubs.
+    pub fn get_user_clubs(
+        env: Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<UserClubSummary>, RunClubError> {
+        let entries = Self::user_club_entries(&env, &user);
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(entries.len());
+
+        let mut clubs = Vec::new(&env);
+        for index in cursor..end {
+            let entry = entries.get(index).unwrap();
+            let Ok(club) = Self::load_club(&env, entry.club_id) else {
+                continue;
+            };
+            clubs.push_back(UserClubSummary {
+                club: Self::club_summary(&club),
+                is_member: entry.is_member,
+                is_organizer: entry.is_organizer,
+            });
+        }
+        Ok(clubs)
+    }
+}
+
+impl RunClubContract {
+    /// KM do usuário na temporada atual do clube
+    pub(crate) fn user_km(env: &Env, user: &Address, club: &Club) -> i128 {
+        let key = DataKey::UserKmTokens(user.clone(), club.id, club.season);
+        let km = env.storage().persistent().get(&key).unwrap_or(0);
+        ttl::bump(env, &key);
+        km
+    }
+}
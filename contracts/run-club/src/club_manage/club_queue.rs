@@ -0,0 +1,198 @@
+//! Filas de endereços de um clube guardadas por posição
+//!
+//! Cada endereço ocupa uma entrada própria na posição em que chegou, com um
+//! índice do endereço para a posição, e `QueueBounds` guarda o início, o fim e
+//! o tamanho da fila. Quem sai do meio deixa a posição vazia; cada entrada guarda
+//! as posições ocupadas vizinhas, então uma saída religa só os vizinhos e o
+//! início da fila vai direto para a próxima posição ocupada.
+
+use core::ops::Range;
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::ttl;
+use crate::{DataKey, QueueBounds, QueueEntry, MAX_PAGE_LIMIT};
+
+/// Filas mantidas para cada clube
+#[derive(Clone, Copy)]
+pub(crate) enum ClubQueue {
+    JoinRequests,
+    Waitlist,
+}
+
+impl ClubQueue {
+    fn bounds_key(self, club_id: u64) -> DataKey {
+        match self {
+            ClubQueue::JoinRequests => DataKey::JoinRequestQueue(club_id),
+            ClubQueue::Waitlist => DataKey::WaitlistQueue(club_id),
+        }
+    }
+
+    fn at_key(self, club_id: u64, position: u32) -> DataKey {
+        match self {
+            ClubQueue::JoinRequests => DataKey::JoinRequestAt(club_id, position),
+            ClubQueue::Waitlist => DataKey::WaitlistAt(club_id, position),
+        }
+    }
+
+    fn index_key(self, club_id: u64, user: &Address) -> DataKey {
+        match self {
+            ClubQueue::JoinRequests => DataKey::JoinRequest(club_id, user.clone()),
+            ClubQueue::Waitlist => DataKey::Waitlisted(club_id, user.clone()),
+        }
+    }
+
+    pub(crate) fn bounds(self, env: &Env, club_id: u64) -> QueueBounds {
+        env.storage()
+            .persistent()
+            .get(&self.bounds_key(club_id))
+            .unwrap_or(QueueBounds {
+                head: 0,
+                last: 0,
+                tail: 0,
+                len: 0,
+            })
+    }
+
+    pub(crate) fn contains(self, env: &Env, club_id: u64, user: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&self.index_key(club_id, user))
+    }
+
+    /// Põe o endereço no fim da fila e devolve o novo tamanho
+    pub(crate) fn push(self, env: &Env, club_id: u64, user: &Address) -> u32 {
+        let mut bounds = self.bounds(env, club_id);
+        let position = bounds.tail;
+        let prev = if bounds.len == 0 {
+            bounds.head = position;
+            None
+        } else {
+            self.update_entry(env, club_id, bounds.last, |entry| {
+                entry.next = Some(position)
+            });
+            Some(bounds.last)
+        };
+        bounds.last = position;
+        bounds.tail += 1;
+        bounds.len += 1;
+
+        let storage = env.storage().persistent();
+        storage.set(
+            &self.at_key(club_id, position),
+            &QueueEntry {
+                user: user.clone(),
+                prev,
+                next: None,
+            },
+        );
+        storage.set(&self.index_key(club_id, user), &position);
+        ttl::bump(env, &self.at_key(club_id, position));
+        ttl::bump(env, &self.index_key(club_id, user));
+        self.write_bounds(env, club_id, &bounds);
+        bounds.len
+    }
+
+    /// Tira o endereço da fila, onde quer que ele esteja
+    pub(crate) fn remove(self, env: &Env, club_id: u64, user: &Address) -> bool {
+        let storage = env.storage().persistent();
+        let Some(position) = storage.get::<_, u32>(&self.index_key(club_id, user)) else {
+            return false;
+        };
+        let at_key = self.at_key(club_id, position);
+        let entry: QueueEntry = storage.get(&at_key).unwrap();
+        storage.remove(&at_key);
+        storage.remove(&self.index_key(club_id, user));
+
+        let mut bounds = self.bounds(env, club_id);
+        bounds.len -= 1;
+        match entry.prev {
+            Some(prev) => self.update_entry(env, club_id, prev, |e| e.next = entry.next),
+            None => bounds.head = entry.next.unwrap_or(bounds.tail),
+        }
+        match entry.next {
+            Some(next) => self.update_entry(env, club_id, next, |e| e.prev = entry.prev),
+            None => bounds.last = entry.prev.unwrap_or(bounds.head),
+        }
+        self.write_bounds(env, club_id, &bounds);
+        true
+    }
+
+    /// Tira o primeiro endereço da fila
+    pub(crate) fn pop_front(self, env: &Env, club_id: u64) -> Option<Address> {
+        let bounds = self.bounds(env, club_id);
+        if bounds.len == 0 {
+            return None;
+        }
+        let entry: QueueEntry = env
+            .storage()
+            .persistent()
+            .get(&self.at_key(club_id, bounds.head))
+            .unwrap();
+        let user = entry.user;
+        self.remove(env, club_id, &user);
+        Some(user)
+    }
+
+    /// Endereços nas posições `cursor..cursor + limit` a partir do início da fila
+    ///
+    /// As posições vazias ficam de fora, então a página pode vir com menos de
+    /// `limit` endereços.
+    pub(crate) fn page(self, env: &Env, club_id: u64, cursor: u32, limit: u32) -> Vec<Address> {
+        let mut users = Vec::new(env);
+        for position in self.window(env, club_id, cursor, limit) {
+            if let Some(entry) = env
+                .storage()
+                .persistent()
+                .get::<_, QueueEntry>(&self.at_key(club_id, position))
+            {
+                users.push_back(entry.user);
+            }
+        }
+        users
+    }
+
+    /// Estende a fila e as entradas nas posições `cursor..cursor + limit` até `ledgers`
+    pub(crate) fn bump_page(self, env: &Env, club_id: u64, cursor: u32, limit: u32, ledgers: u32) {
+        ttl::bump_until(env, &self.bounds_key(club_id), ledgers);
+
+        for position in self.window(env, club_id, cursor, limit) {
+            let key = self.at_key(club_id, position);
+            if let Some(entry) = env.storage().persistent().get::<_, QueueEntry>(&key) {
+                ttl::bump_until(env, &key, ledgers);
+                ttl::bump_until(env, &self.index_key(club_id, &entry.user), ledgers);
+            }
+        }
+    }
+
+    /// Esvazia a fila de um clube que está sendo removido
+    ///
+    /// Só os limites são apagados; as entradas ficam sem uso até expirarem.
+    pub(crate) fn clear(self, env: &Env, club_id: u64) {
+        env.storage().persistent().remove(&self.bounds_key(club_id));
+    }
+
+    /// Posições `cursor..cursor + limit` contadas a partir do início da fila
+    fn window(self, env: &Env, club_id: u64, cursor: u32, limit: u32) -> Range<u32> {
+        let bounds = self.bounds(env, club_id);
+        let start = bounds.head.saturating_add(cursor).min(bounds.tail);
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(bounds.tail);
+        start..end
+    }
+
+    /// Altera a entrada ocupada em `position`, como ao religar um vizinho
+    fn update_entry(self, env: &Env, club_id: u64, position: u32, f: impl FnOnce(&mut QueueEntry)) {
+        let key = self.at_key(club_id, position);
+        let mut entry: QueueEntry = env.storage().persistent().get(&key).unwrap();
+        f(&mut entry);
+        env.storage().persistent().set(&key, &entry);
+    }
+
+    fn write_bounds(self, env: &Env, club_id: u64, bounds: &QueueBounds) {
+        let key = self.bounds_key(club_id);
+        env.storage().persistent().set(&key, bounds);
+        ttl::bump(env, &key);
+    }
+}
@@ -0,0 +1,101 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Vec};
+
+use crate::ttl;
+use crate::{
+    Club, DataKey, RunClubContract, RunClubContractArgs, RunClubContractClient, RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Autoriza um endereço a enviar corridas do clube sem co-assinatura do
+    /// oráculo (apenas organizador)
+    pub fn add_recorder(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        recorder: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        let mut recorders = club_recorders(&env, club_id);
+        if recorders.contains(&recorder) {
+            return Err(RunClubError::RecorderAlreadyExists);
+        }
+        recorders.push_back(recorder.clone());
+        write_club_recorders(&env, club_id, &recorders);
+
+        env.events()
+            .publish((symbol_short!("rec_add"), club_id), recorder);
+
+        Ok(())
+    }
+
+    /// Remove um registrador do clube (apenas organizador)
+    pub fn remove_recorder(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        recorder: Address,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        let mut recorders = club_recorders(&env, club_id);
+        let index = recorders
+            .first_index_of(&recorder)
+            .ok_or(RunClubError::RecorderNotFound)?;
+        recorders.remove(index);
+        write_club_recorders(&env, club_id, &recorders);
+
+        env.events()
+            .publish((symbol_short!("rec_del"), club_id), recorder);
+
+        Ok(())
+    }
+
+    /// Registradores do clube
+    pub fn get_recorders(env: Env, club_id: u64) -> Result<Vec<Address>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(club_recorders(&env, club_id))
+    }
+}
+
+impl RunClubContract {
+    /// Garante que o endereço é um registrador do clube
+    pub(crate) fn require_recorder(
+        env: &Env,
+        club: &Club,
+        recorder: &Address,
+    ) -> Result<(), RunClubError> {
+        if !club_recorders(env, club.id).contains(recorder) {
+            return Err(RunClubError::NotRecorder);
+        }
+        Ok(())
+    }
+}
+
+fn club_recorders(env: &Env, club_id: u64) -> Vec<Address> {
+    let key = DataKey::ClubRecorders(club_id);
+    let recorders = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    ttl::bump(env, &key);
+    recorders
+}
+
+fn write_club_recorders(env: &Env, club_id: u64, recorders: &Vec<Address>) {
+    let key = DataKey::ClubRecorders(club_id);
+    if recorders.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, recorders);
+        ttl::bump(env, &key);
+    }
+}
@@ -0,0 +1,115 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::ttl;
+use crate::{Club, ClubStatus, ClubSummary, DataKey, RunClubContract, MAX_PAGE_LIMIT};
+
+impl RunClubContract {
+    /// Coloca um clube novo no registro de clubes em rascunho
+    pub(crate) fn register_club(env: &Env, club: &mut Club) {
+        club.status = ClubStatus::Draft;
+        club.is_active = false;
+        Self::push_status_entry(env, club.id, ClubStatus::Draft);
+    }
+
+    /// Move o clube para outro estado do ciclo de vida, atualizando o registro
+    pub(crate) fn set_club_status(env: &Env, club: &mut Club, status: ClubStatus) {
+        if club.status == status {
+            return;
+        }
+
+        Self::remove_status_entry(env, club.id, club.status.clone());
+        Self::push_status_entry(env, club.id, status.clone());
+
+        club.is_active = status == ClubStatus::Active;
+        club.status = status;
+    }
+
+    /// Encerra o clube se o período já terminou e ele ainda estava aberto
+    pub(crate) fn end_club_if_expired(env: &Env, club: &mut Club) -> bool {
+        if Self::is_expired(env, club) {
+            Self::set_club_status(env, club, ClubStatus::Ended);
+            return true;
+        }
+        false
+    }
+
+    /// Clube ainda aberto (`Draft` ou `Active`) cujo período já terminou
+    pub(crate) fn is_expired(env: &Env, club: &Club) -> bool {
+        matches!(club.status, ClubStatus::Draft | ClubStatus::Active)
+            && env.ledger().timestamp() > club.month_end_timestamp
+    }
+
+    /// Resumos dos clubes em um estado, a partir da posição `cursor`
+    ///
+    /// Clubes abertos com o período vencido só passam a `Ended` na próxima escrita;
+    /// até lá ficam fora da página, que pode vir com menos de `limit` clubes.
+    pub(crate) fn status_page(
+        env: &Env,
+        status: ClubStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<ClubSummary> {
+        let count = Self::status_count(env, status.clone());
+        let end = cursor.saturating_add(limit.min(MAX_PAGE_LIMIT)).min(count);
+
+        let mut clubs = Vec::new(env);
+        for index in cursor..end {
+            let club_id: u64 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ClubsByStatus(status.clone(), index))
+                .unwrap();
+            let club: Club = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Club(club_id))
+                .unwrap();
+            if !Self::is_expired(env, &club) {
+                clubs.push_back(Self::club_summary(&club));
+            }
+        }
+        clubs
+    }
+
+    pub(crate) fn status_count(env: &Env, status: ClubStatus) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClubStatusCount(status))
+            .unwrap_or(0)
+    }
+
+    /// Coloca o clube no fim da lista do estado
+    pub(crate) fn push_status_entry(env: &Env, club_id: u64, status: ClubStatus) {
+        let storage = env.storage().persistent();
+        let index = Self::status_count(env, status.clone());
+
+        storage.set(&DataKey::ClubsByStatus(status.clone(), index), &club_id);
+        storage.set(&DataKey::ClubStatusIndex(club_id), &index);
+        storage.set(&DataKey::ClubStatusCount(status.clone()), &(index + 1));
+
+        ttl::bump(env, &DataKey::ClubsByStatus(status.clone(), index));
+        ttl::bump(env, &DataKey::ClubStatusIndex(club_id));
+        ttl::bump(env, &DataKey::ClubStatusCount(status));
+    }
+
+    /// Tira o clube da lista do estado, movendo o último para a posição liberada
+    ///
+    /// `ClubStatusIndex` do clube fica para o `push_status_entry` regravar, sem perder
+    /// o TTL estendido pelo `bump_club`.
+    fn remove_status_entry(env: &Env, club_id: u64, status: ClubStatus) {
+        let storage = env.storage().persistent();
+        let index: u32 = storage.get(&DataKey::ClubStatusIndex(club_id)).unwrap();
+        let last_index = Self::status_count(env, status.clone()) - 1;
+
+        if index != last_index {
+            let last: u64 = storage
+                .get(&DataKey::ClubsByStatus(status.clone(), last_index))
+                .unwrap();
+            storage.set(&DataKey::ClubsByStatus(status.clone(), index), &last);
+            storage.set(&DataKey::ClubStatusIndex(last), &index);
+        }
+
+        storage.remove(&DataKey::ClubsByStatus(status.clone(), last_index));
+        storage.set(&DataKey::ClubStatusCount(status), &last_index);
+    }
+}
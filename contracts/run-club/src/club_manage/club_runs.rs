@@ -0,0 +1,207 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::token_factory;
+use crate::ttl;
+use crate::{Club, DataKey, Run, RunAttestation, RunClubContract, RunClubError, MAX_PAGE_LIMIT};
+
+const DAY_IN_SECONDS: u64 = 24 * 60 * 60;
+
+impl RunClubContract {
+    /// Carrega o clube de uma corrida e confere se ela pode ser creditada, devolvendo
+    /// os KM em unidades do token
+    pub(crate) fn prepare_run(
+        env: &Env,
+        attestation: &RunAttestation,
+    ) -> Result<(Club, i128), RunClubError> {
+        let km_amount = token_factory::km_from_meters(attestation.distance_meters);
+        if km_amount <= 0 {
+            return Err(RunClubError::InvalidAmount);
+        }
+
+        let club = Self::load_club(env, attestation.club_id)?;
+
+        if !club.is_active {
+            return Err(RunClubError::ClubNotActive);
+        }
+
+        Self::require_member(env, &club, &attestation.user)?;
+
+        if env.ledger().timestamp() > club.month_end_timestamp {
+            return Err(RunClubError::ClubPeriodEnded);
+        }
+
+        // Só contam corridas feitas inteiras dentro do período ativo
+        if attestation.start_timestamp < club.start_timestamp
+            || attestation.end_timestamp > club.month_end_timestamp
+        {
+            return Err(RunClubError::RunOutsidePeriod);
+        }
+
+        Ok((club, km_amount))
+    }
+
+    /// Registra uma corrida já autorizada e emite os tokens KM para o corredor
+    pub(crate) fn credit_run(
+        env: &Env,
+        club: &Club,
+        attestation: &RunAttestation,
+        km_amount: i128,
+    ) -> Result<(), RunClubError> {
+        let user = &attestation.user;
+        Self::check_run_limits(env, club, attestation)?;
+        Self::record_run(env, club, attestation, km_amount)?;
+
+        let key = DataKey::UserKmTokens(user.clone(), club.id, club.season);
+        let current: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(current + km_amount));
+        ttl::bump(env, &key);
+        Self::update_km_totals(env, club, current, current + km_amount);
+
+        // Emitir os tokens KM na carteira do corredor
+        token_factory::TokenClient::new(env, &club.token_address).mint(user, &km_amount);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("km_add"),),
+            (club.id, user.clone(), km_amount, attestation.run_id.clone()),
+        );
+
+        Ok(())
+    }
+
+    /// Rejeita corridas fora dos limites de plausibilidade do clube
+    pub(crate) fn check_run_limits(
+        env: &Env,
+        club: &Club,
+        attestation: &RunAttestation,
+    ) -> Result<(), RunClubError> {
+        let limits = &club.limits;
+        let meters = attestation.distance_meters as u128;
+        let duration = attestation.end_timestamp - attestation.start_timestamp;
+
+        if limits.min_duration_secs > 0 && duration < limits.min_duration_secs {
+            return Err(RunClubError::RunTooShort);
+        }
+
+        if limits.max_distance_meters > 0
+            && attestation.distance_meters > limits.max_distance_meters
+        {
+            return Err(RunClubError::RunTooLong);
+        }
+
+        // Ritmo em s/km = duração * 1000 / metros, comparado sem divisão
+        let scaled_duration = duration as u128 * 1000;
+        if limits.min_pace_secs_per_km > 0
+            && scaled_duration < limits.min_pace_secs_per_km as u128 * meters
+        {
+            return Err(RunClubError::RunTooFast);
+        }
+        if limits.max_pace_secs_per_km > 0
+            && scaled_duration > limits.max_pace_secs_per_km as u128 * meters
+        {
+            return Err(RunClubError::RunTooSlow);
+        }
+
+        if limits.max_daily_meters > 0 {
+            let ran_today = Self::daily_meters(env, attestation);
+            if ran_today + attestation.distance_meters > limits.max_daily_meters {
+                return Err(RunClubError::DailyLimitExceeded);
+            }
+        }
+        Ok(())
+    }
+
+    /// Metros já creditados ao corredor no dia (UTC) em que a corrida terminou
+    fn daily_meters(env: &Env, attestation: &RunAttestation) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&Self::daily_key(attestation))
+            .unwrap_or(0)
+    }
+
+    fn daily_key(attestation: &RunAttestation) -> DataKey {
+        Self::daily_meters_key(
+            attestation.club_id,
+            &attestation.user,
+            attestation.end_timestamp,
+        )
+    }
+
+    /// Chave dos metros do corredor no dia (UTC) de `end_timestamp`
+    pub(crate) fn daily_meters_key(club_id: u64, user: &Address, end_timestamp: u64) -> DataKey {
+        DataKey::DailyMeters(club_id, user.clone(), end_timestamp / DAY_IN_SECONDS)
+    }
+
+    /// Registra uma corrida no histórico do corredor, rejeitando run ids repetidos no clube
+    pub(crate) fn record_run(
+        env: &Env,
+        club: &Club,
+        attestation: &RunAttestation,
+        km: i128,
+    ) -> Result<Run, RunClubError> {
+        let club_id = attestation.club_id;
+        let user = &attestation.user;
+        let storage = env.storage().persistent();
+
+        let run_id_key = DataKey::RunId(club_id, attestation.run_id.clone());
+        if storage.has(&run_id_key) {
+            return Err(RunClubError::DuplicateRun);
+        }
+
+        let run = Run {
+            run_id: attestation.run_id.clone(),
+            distance_meters: attestation.distance_meters,
+            km,
+            season: club.season,
+            start_timestamp: attestation.start_timestamp,
+            end_timestamp: attestation.end_timestamp,
+            recorded_at: env.ledger().timestamp(),
+        };
+
+        let index = Self::run_count(env, club_id, user);
+        let run_key = DataKey::Run(club_id, user.clone(), index);
+        let count_key = DataKey::RunCount(club_id, user.clone());
+        storage.set(&run_key, &run);
+        storage.set(&count_key, &(index + 1));
+        storage.set(&run_id_key, &true);
+
+        let daily_key = Self::daily_key(attestation);
+        let ran_today = Self::daily_meters(env, attestation) + attestation.distance_meters;
+        storage.set(&daily_key, &ran_today);
+        ttl::bump(env, &daily_key);
+
+        ttl::bump(env, &run_key);
+        ttl::bump(env, &count_key);
+        ttl::bump(env, &run_id_key);
+
+        Ok(run)
+    }
+
+    pub(crate) fn run_count(env: &Env, club_id: u64, user: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RunCount(club_id, user.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Corridas do usuário a partir da posição `cursor`, da mais antiga para a mais recente
+    pub(crate) fn run_page(
+        env: &Env,
+        club_id: u64,
+        user: &Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<Run> {
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(Self::run_count(env, club_id, user));
+
+        let mut runs = Vec::new(env);
+        for index in cursor..end {
+            let key = DataKey::Run(club_id, user.clone(), index);
+            let run: Run = env.storage().persistent().get(&key).unwrap();
+            ttl::bump(env, &key);
+            runs.push_back(run);
+        }
+        runs
+    }
+}
@@ -0,0 +1,177 @@
+use soroban_sdk::{contractimpl, symbol_short, token, Address, Env, Vec};
+
+use crate::redemption::reward_rules::{empty_totals, tally, untally};
+use crate::ttl;
+use crate::{
+    Club, ClubPermission, ClubStatus, DataKey, KmTotals, Payout, RunClubContract,
+    RunClubContractArgs, RunClubContractClient, RunClubError, Season, Settlement, MAX_PAGE_LIMIT,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Abre a próxima temporada do clube com os mesmos membros (organizador ou
+    /// co-organizador com `Settlement`)
+    ///
+    /// Fecha a temporada atual se ninguém a fechou ainda. Enquanto houver valores
+    /// a resgatar, só depois do prazo de resgate. Os KM recomeçam do zero; o USDC
+    /// que sobrou no pool passa para a nova temporada com `rollover`, ou volta ao
+    /// organizador sem ele. O calendário segue as regras do `create_club`.
+    pub fn start_new_season(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        start_timestamp: u64,
+        registration_close_timestamp: u64,
+        end_timestamp: u64,
+        rollover: bool,
+    ) -> Result<u32, RunClubError> {
+        organizer.require_auth();
+
+        Self::validate_schedule(
+            &env,
+            start_timestamp,
+            registration_close_timestamp,
+            end_timestamp,
+        )?;
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Settlement)?;
+
+        let mut settlement = Self::settle_if_needed(&env, &mut club)?;
+        if settlement.claimed < settlement.total_payout
+            && env.ledger().timestamp() <= settlement.claim_deadline
+        {
+            return Err(RunClubError::ClaimWindowOpen);
+        }
+
+        let leftover = club.usdc_deposited;
+        let rolled_over = if rollover { leftover } else { 0 };
+        if !rollover && leftover > 0 {
+            club.usdc_deposited = 0;
+            settlement.swept += leftover;
+            Self::write_settlement(&env, &club, &settlement);
+
+            let usdc = token::Client::new(&env, &Self::usdc_token(&env)?);
+            usdc.transfer(&env.current_contract_address(), &club.organizer, &leftover);
+        }
+
+        club.season += 1;
+        club.start_timestamp = start_timestamp;
+        club.registration_close_timestamp = registration_close_timestamp;
+        club.month_end_timestamp = end_timestamp;
+        Self::write_season(
+            &env,
+            club_id,
+            &Season {
+                season: club.season,
+                start_timestamp,
+                end_timestamp,
+                rolled_over,
+            },
+        );
+        Self::set_club_status(&env, &mut club, ClubStatus::Active);
+        Self::save_club(&env, &mut club);
+
+        env.events().publish(
+            (symbol_short!("season"),),
+            (club_id, club.season, rolled_over),
+        );
+
+        Ok(club.season)
+    }
+
+    /// Obtém uma temporada do clube
+    pub fn get_season(env: Env, club_id: u64, season: u32) -> Result<Season, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Self::season(&env, club_id, season).ok_or(RunClubError::SeasonNotFound)
+    }
+
+    /// Obtém uma página das temporadas do clube, da primeira para a atual
+    pub fn get_seasons(
+        env: Env,
+        club_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Season>, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        let end = cursor
+            .saturating_add(limit.min(MAX_PAGE_LIMIT))
+            .min(club.season);
+
+        let mut seasons = Vec::new(&env);
+        for index in cursor..end {
+            if let Some(season) = Self::season(&env, club_id, index + 1) {
+                seasons.push_back(season);
+            }
+        }
+        Ok(seasons)
+    }
+
+    /// Fechamento de uma temporada do clube
+    pub fn get_season_settlement(
+        env: Env,
+        club_id: u64,
+        season: u32,
+    ) -> Result<Settlement, RunClubError> {
+        Self::get_season(env.clone(), club_id, season)?;
+        let key = DataKey::Settlement(club_id, season);
+        let settlement = env.storage().persistent().get(&key);
+        ttl::bump(&env, &key);
+        settlement.ok_or(RunClubError::ClubNotSettled)
+    }
+
+    /// Valor fixado para um membro no fechamento de uma temporada do clube
+    pub fn get_season_payout(
+        env: Env,
+        club_id: u64,
+        season: u32,
+        user: Address,
+    ) -> Result<Payout, RunClubError> {
+        Self::get_season_settlement(env.clone(), club_id, season)?;
+        let key = DataKey::Payout(club_id, season, user);
+        let payout = env.storage().persistent().get(&key);
+        ttl::bump(&env, &key);
+        payout.ok_or(RunClubError::NoKmTokens)
+    }
+}
+
+impl RunClubContract {
+    pub(crate) fn season(env: &Env, club_id: u64, season: u32) -> Option<Season> {
+        let key = DataKey::Season(club_id, season);
+        let record = env.storage().persistent().get(&key);
+        ttl::bump(env, &key);
+        record
+    }
+
+    pub(crate) fn write_season(env: &Env, club_id: u64, season: &Season) {
+        let key = DataKey::Season(club_id, season.season);
+        env.storage().persistent().set(&key, season);
+        ttl::bump(env, &key);
+    }
+
+    /// Totais dos KM dos membros na temporada atual
+    pub(crate) fn km_totals(env: &Env, club: &Club) -> KmTotals {
+        let key = DataKey::KmTotals(club.id, club.season);
+        let totals = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| empty_totals(env));
+        ttl::bump(env, &key);
+        totals
+    }
+
+    /// Troca os KM de um membro de `old_km` para `new_km` nos totais da temporada atual
+    pub(crate) fn update_km_totals(env: &Env, club: &Club, old_km: i128, new_km: i128) {
+        if old_km == new_km {
+            return;
+        }
+        let mut totals = Self::km_totals(env, club);
+        untally(&club.withdrawal_rule, &mut totals, old_km);
+        tally(&club.withdrawal_rule, &mut totals, new_km);
+
+        let key = DataKey::KmTotals(club.id, club.season);
+        env.storage().persistent().set(&key, &totals);
+        ttl::bump(env, &key);
+    }
+}
@@ -1,11 +1,174 @@
 use soroban_sdk::{Address, Env};
 
-use crate::{Club, DataKey, RunClubContract};
+use crate::redemption::reward_rules::BPS_DENOMINATOR;
+use crate::ttl;
+use crate::{
+    Club, ClubPermission, ClubStatus, DataKey, RunClubContract, RunClubError, RunLimits,
+    WithdrawalRule, MAX_PODIUM_PLACES, MAX_REWARD_TIERS,
+};
 
 impl RunClubContract {
+    /// Carrega um clube, falhando se ele não existir
+    pub(crate) fn load_club(env: &Env, club_id: u64) -> Result<Club, RunClubError> {
+        let club = Self::load_club_record(env, club_id)?;
+
+        // Clubes removidos ficam no registro, mas não existem para as operações
+        if club.status == ClubStatus::Removed {
+            return Err(RunClubError::ClubNotFound);
+        }
+        Ok(club)
+    }
+
+    /// Carrega um clube do registro, removido inclusive
+    pub(crate) fn load_club_record(env: &Env, club_id: u64) -> Result<Club, RunClubError> {
+        let club: Club = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Club(club_id))
+            .ok_or(RunClubError::ClubNotFound)?;
+        ttl::bump(env, &DataKey::Club(club_id));
+        Ok(club)
+    }
+
+    /// Salva um clube e estende o TTL da entrada
+    ///
+    /// Um clube aberto cujo período já terminou passa a `Ended` antes de ser gravado,
+    /// saindo da lista de clubes ativos sem esperar o `end_club`.
+    pub(crate) fn save_club(env: &Env, club: &mut Club) {
+        Self::end_club_if_expired(env, club);
+        let key = DataKey::Club(club.id);
+        env.storage().persistent().set(&key, club);
+        ttl::bump(env, &key);
+        ttl::bump_instance(env);
+        ttl::bump_contract(env, &club.token_address);
+    }
+
+    /// Garante que o endereço é o organizador do clube
+    pub(crate) fn require_organizer(club: &Club, organizer: &Address) -> Result<(), RunClubError> {
+        if club.organizer != *organizer {
+            return Err(RunClubError::NotOrganizer);
+        }
+        Ok(())
+    }
+
+    /// Garante que o endereço é o organizador ou um co-organizador com o escopo
+    /// `permission`
+    pub(crate) fn require_permission(
+        env: &Env,
+        club: &Club,
+        caller: &Address,
+        permission: ClubPermission,
+    ) -> Result<(), RunClubError> {
+        if club.organizer == *caller {
+            return Ok(());
+        }
+
+        let allowed = Self::co_organizers(env, club.id)
+            .iter()
+            .any(|co| co.address == *caller && co.permissions.contains(&permission));
+        if !allowed {
+            return Err(RunClubError::NotOrganizer);
+        }
+        Ok(())
+    }
+
+    /// Garante que o usuário é membro do clube
+    pub(crate) fn require_member(
+        env: &Env,
+        club: &Club,
+        user: &Address,
+    ) -> Result<(), RunClubError> {
+        if Self::member(env, club.id, user).is_none() {
+            return Err(RunClubError::NotMember);
+        }
+        Ok(())
+    }
+
+    /// Garante que a temporada começa antes de terminar, que as inscrições fecham
+    /// até o fim e que o fim ainda não passou
+    pub(crate) fn validate_schedule(
+        env: &Env,
+        start_timestamp: u64,
+        registration_close_timestamp: u64,
+        end_timestamp: u64,
+    ) -> Result<(), RunClubError> {
+        if end_timestamp <= start_timestamp {
+            return Err(RunClubError::InvalidDuration);
+        }
+        if registration_close_timestamp > end_timestamp || end_timestamp <= env.ledger().timestamp()
+        {
+            return Err(RunClubError::InvalidSchedule);
+        }
+        Ok(())
+    }
+
+    /// Garante que os parâmetros da regra de resgate são coerentes
+    pub(crate) fn validate_withdrawal_rule(rule: &WithdrawalRule) -> Result<(), RunClubError> {
+        match rule {
+            WithdrawalRule::Equal | WithdrawalRule::Unlimited => Ok(()),
+            WithdrawalRule::Tiered(tiers) => {
+                if tiers.is_empty() || tiers.len() > MAX_REWARD_TIERS {
+                    return Err(RunClubError::InvalidWithdrawalRule);
+                }
+                // Faixas em ordem crescente de KM, cada uma pagando algo
+                let mut previous: Option<i128> = None;
+                for tier in tiers.iter() {
+                    if tier.min_km < 0
+                        || tier.reward <= 0
+                        || previous.is_some_and(|min_km| tier.min_km <= min_km)
+                    {
+                        return Err(RunClubError::InvalidWithdrawalRule);
+                    }
+                    previous = Some(tier.min_km);
+                }
+                Ok(())
+            }
+            WithdrawalRule::TopN(places) => {
+                if places.is_empty() || places.len() > MAX_PODIUM_PLACES {
+                    return Err(RunClubError::InvalidWithdrawalRule);
+                }
+                let mut total_bps = 0u32;
+                for bps in places.iter() {
+                    if bps == 0 {
+                        return Err(RunClubError::InvalidWithdrawalRule);
+                    }
+                    total_bps += bps;
+                }
+                if total_bps > BPS_DENOMINATOR {
+                    return Err(RunClubError::InvalidWithdrawalRule);
+                }
+                Ok(())
+            }
+            WithdrawalRule::Threshold(min_km) => {
+                if *min_km <= 0 {
+                    return Err(RunClubError::InvalidWithdrawalRule);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Garante que os limites de corrida são coerentes entre si
+    pub(crate) fn validate_run_limits(limits: &RunLimits) -> Result<(), RunClubError> {
+        if limits.min_pace_secs_per_km > 0
+            && limits.max_pace_secs_per_km > 0
+            && limits.min_pace_secs_per_km > limits.max_pace_secs_per_km
+        {
+            return Err(RunClubError::InvalidRunLimits);
+        }
+
+        if limits.max_distance_meters > 0
+            && limits.max_daily_meters > 0
+            && limits.max_distance_meters > limits.max_daily_meters
+        {
+            return Err(RunClubError::InvalidRunLimits);
+        }
+        Ok(())
+    }
+
     /// Verifica se um clube existe
     pub fn club_exists(env: Env, club_id: u64) -> bool {
-        env.storage().persistent().has(&DataKey::Club(club_id))
+        Self::load_club(&env, club_id).is_ok()
     }
 
     /// Verifica se um clube está ativo
@@ -21,7 +184,7 @@ impl RunClubContract {
         }
     }
 
-    /// Verifica se um clube ainda está dentro do período válido
+    /// Verifica se um clube está dentro do período ativo
     pub fn is_club_period_valid(env: Env, club_id: u64) -> bool {
         if let Some(club) = env
             .storage()
@@ -29,7 +192,8 @@ impl RunClubContract {
             .get::<DataKey, Club>(&DataKey::Club(club_id))
         {
             let current_timestamp = env.ledger().timestamp();
-            current_timestamp <= club.month_end_timestamp
+            club.start_timestamp <= current_timestamp
+                && current_timestamp <= club.month_end_timestamp
         } else {
             false
         }
@@ -55,7 +219,7 @@ impl RunClubContract {
             .persistent()
             .get::<DataKey, Club>(&DataKey::Club(club_id))
         {
-            !club.members.is_empty()
+            club.member_count > 0
         } else {
             false
         }
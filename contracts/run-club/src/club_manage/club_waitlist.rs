@@ -0,0 +1,129 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Vec};
+
+use super::club_queue::ClubQueue;
+use crate::{
+    Club, Member, MemberRole, RemovedKmPolicy, RunClubContract, RunClubContractArgs,
+    RunClubContractClient, RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Sai do clube ou da sua fila de espera
+    ///
+    /// Os KM de quem sai seguem a `RemovedKmPolicy` do clube, e o primeiro da fila
+    /// de espera fica com a vaga.
+    pub fn leave_club(env: Env, club_id: u64, member: Address) -> Result<(), RunClubError> {
+        member.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+
+        if let Some(entry) = Self::member(&env, club_id, &member) {
+            Self::release_member(&env, &mut club, &member, &entry)?;
+            Self::save_club(&env, &mut club);
+
+            env.events()
+                .publish((symbol_short!("mem_left"),), (club_id, member));
+            return Ok(());
+        }
+
+        if !ClubQueue::Waitlist.remove(&env, club_id, &member) {
+            return Err(RunClubError::NotMember);
+        }
+
+        env.events()
+            .publish((symbol_short!("wait_del"), club_id), member);
+
+        Ok(())
+    }
+
+    /// Fila de espera do clube, do primeiro ao último
+    ///
+    /// `cursor` conta posições da fila, e as deixadas por quem saiu do meio ficam de
+    /// fora, então a página pode vir com menos de `limit` endereços.
+    pub fn get_waitlist(
+        env: Env,
+        club_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(ClubQueue::Waitlist.page(&env, club_id, cursor, limit))
+    }
+}
+
+impl RunClubContract {
+    /// Se o clube já ocupou todas as vagas
+    pub(crate) fn is_full(club: &Club) -> bool {
+        club.max_members
+            .is_some_and(|max_members| club.member_count >= max_members)
+    }
+
+    /// Põe o endereço no fim da fila de espera
+    pub(crate) fn enqueue_waitlist(
+        env: &Env,
+        club_id: u64,
+        user: &Address,
+    ) -> Result<(), RunClubError> {
+        if ClubQueue::Waitlist.contains(env, club_id, user) {
+            return Err(RunClubError::MemberAlreadyExists);
+        }
+        let len = ClubQueue::Waitlist.push(env, club_id, user);
+
+        env.events()
+            .publish((symbol_short!("wait_add"), club_id), (user.clone(), len));
+
+        Ok(())
+    }
+
+    /// Tira um membro do clube, aplica a política dos KM de quem sai e passa a
+    /// vaga ao primeiro da fila de espera
+    ///
+    /// Com um fechamento em andamento a lista de membros não muda, para que as
+    /// próximas páginas de `settle_club` não pulem ninguém.
+    pub(crate) fn release_member(
+        env: &Env,
+        club: &mut Club,
+        user: &Address,
+        member: &Member,
+    ) -> Result<(), RunClubError> {
+        if Self::settlement_draft(env, club).is_some() {
+            return Err(RunClubError::ClubNotSettled);
+        }
+
+        Self::delete_member(env, club, user, member);
+        if club.removed_km_policy == RemovedKmPolicy::Forfeit {
+            Self::forfeit_km(env, club, user);
+        }
+        Self::promote_waitlisted(env, club);
+        Ok(())
+    }
+
+    /// Apaga a fila de espera de um clube que está sendo removido
+    pub(crate) fn clear_waitlist(env: &Env, club_id: u64) {
+        ClubQueue::Waitlist.clear(env, club_id);
+    }
+
+    /// Inscreve o primeiro da fila de espera, se houver vaga
+    ///
+    /// Quem entrou na fila antes do fim das inscrições mantém o lugar depois dele.
+    fn promote_waitlisted(env: &Env, club: &mut Club) {
+        if Self::is_full(club) {
+            return;
+        }
+        let Some(user) = ClubQueue::Waitlist.pop_front(env, club.id) else {
+            return;
+        };
+
+        let role = if user == club.organizer {
+            MemberRole::Organizer
+        } else {
+            MemberRole::Member
+        };
+        Self::insert_member(env, club, &user, role, env.ledger().timestamp());
+
+        env.events()
+            .publish((symbol_short!("wait_out"), club.id), user.clone());
+        env.events()
+            .publish((symbol_short!("mem_add"),), (club.id, user));
+    }
+}
@@ -1,9 +1,18 @@
 //! Módulo para gerenciamento de clubes de corrida
-//! 
+//!
 //! Este módulo contém todas as funcionalidades relacionadas ao
 //! gerenciamento de clubes, incluindo criação, atualização,
 //! desativação e consultas de clubes.
 
+pub mod club_admission;
+pub mod club_members;
 pub mod club_operations;
+pub mod club_organizers;
 pub mod club_queries;
+pub mod club_queue;
+pub mod club_recorders;
+pub mod club_registry;
+pub mod club_runs;
+pub mod club_seasons;
 pub mod club_validation;
+pub mod club_waitlist;
@@ -0,0 +1,72 @@
+use soroban_sdk::contracterror;
+
+/// Erros retornados pelo contrato
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RunClubError {
+    // Configuração do contrato
+    NotInitialized = 1,
+    InvalidPlatformFee = 3,
+    UnsupportedSchemaVersion = 4,
+
+    // Gerenciamento de clubes
+    ClubNotFound = 10,
+    NotOrganizer = 11,
+    InvalidUsdcPerKm = 12,
+    InvalidDuration = 13,
+    InvalidAmount = 14,
+    ClubNotActive = 15,
+    ClubPeriodEnded = 16,
+    ClubHasDeposits = 17,
+    InvalidWithdrawalRule = 18,
+    InvalidSchedule = 19,
+
+    // Membros
+    MemberAlreadyExists = 20,
+    MemberNotFound = 21,
+    NotMember = 22,
+    RegistrationClosed = 23,
+    MembershipRestricted = 24,
+    InvalidInvite = 25,
+    InvalidCapacity = 26,
+
+    // Token KM
+    InsufficientBalance = 31,
+
+    // Resgate
+    ClubPeriodNotEnded = 40,
+    NoKmTokens = 41,
+    NoSurplus = 42,
+    ClubNotSettled = 43,
+    ClubAlreadySettled = 44,
+    ClaimWindowClosed = 45,
+    ClaimWindowOpen = 46,
+    NothingToSweep = 47,
+    SeasonNotFound = 48,
+
+    // Oráculos
+    OracleAlreadyExists = 50,
+    OracleNotFound = 51,
+    UnknownOracle = 52,
+    InvalidAttestation = 53,
+    NonceAlreadyUsed = 54,
+    RecorderAlreadyExists = 55,
+    RecorderNotFound = 56,
+    NotRecorder = 57,
+
+    // Corridas
+    DuplicateRun = 60,
+    InvalidRunLimits = 61,
+    RunTooFast = 62,
+    RunTooSlow = 63,
+    RunTooLong = 64,
+    RunTooShort = 65,
+    DailyLimitExceeded = 66,
+    RunOutsidePeriod = 67,
+
+    // Organizadores
+    NotPendingOrganizer = 70,
+    CoOrganizerNotFound = 71,
+    InvalidPermissions = 72,
+}
@@ -1,11 +1,127 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, BytesN, Env, String, Vec};
+
+mod club_manage;
+mod error;
+mod oracle;
+mod redemption;
+mod token_factory;
+mod ttl;
+mod upgrade;
+
+pub use error::RunClubError;
+pub use token_factory::{KM_TOKEN_DECIMALS, KM_UNIT, KM_UNITS_PER_METER};
+pub use ttl::{
+    LEDGER_CLOSE_SECONDS, MAX_BUMP_PAGE, PERSISTENT_BUMP_AMOUNT, REDEMPTION_GRACE_PERIOD,
+};
+pub use upgrade::SCHEMA_VERSION;
+
+/// Tamanho máximo de página das consultas paginadas
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// Número máximo de colocações pagas na regra `TopN`
+pub const MAX_PODIUM_PLACES: u32 = 10;
+
+/// Número máximo de faixas na regra `Tiered`
+pub const MAX_REWARD_TIERS: u32 = 10;
+
+/// Faixa de recompensa da regra `Tiered`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardTier {
+    /// KM mínimo da faixa, em unidades do token
+    pub min_km: i128,
+    /// USDC pago a quem termina na faixa
+    pub reward: i128,
+}
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum WithdrawalRule {
     Equal,
     Unlimited,
+    /// Valor fixo pela faixa de KM alcançada, reduzido na proporção se o pool não cobrir
+    Tiered(Vec<RewardTier>),
+    /// Pódio: fatia do pool em basis points para o 1º, 2º, ... colocados
+    TopN(Vec<u32>),
+    /// Pool dividido igualmente entre quem alcança o KM mínimo (unidades do token)
+    Threshold(i128),
+}
+
+/// Destino do USDC que sobra quando a taxa `usdc_per_km` não consome o pool
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SurplusRule {
+    /// Cada corredor recebe km × taxa; a sobra volta para o organizador
+    ReturnToOrganizer,
+    /// A sobra é dividida entre os corredores na proporção dos KM
+    ProRata,
+}
+
+/// Destino dos KM de um membro removido do clube
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RemovedKmPolicy {
+    /// Os KM da temporada são zerados e o valor fixado ainda não resgatado volta ao pool
+    Forfeit,
+    /// Os KM ficam guardados, fora do fechamento e do resgate, até o membro voltar
+    Freeze,
+}
+
+/// Como novos membros entram no clube
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MembershipPolicy {
+    /// Qualquer endereço entra com `add_member`
+    Open,
+    /// O endereço pede para entrar e o organizador aprova ou recusa
+    ApprovalRequired,
+    /// Só entra quem revela um código de convite
+    InviteOnly,
+}
+
+/// Limites de plausibilidade das corridas de um clube (0 desliga o limite)
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RunLimits {
+    /// Ritmo mais rápido aceito, em segundos por km
+    pub min_pace_secs_per_km: u32,
+    /// Ritmo mais lento aceito, em segundos por km
+    pub max_pace_secs_per_km: u32,
+    pub max_distance_meters: u64,
+    /// Distância máxima creditada por membro em um dia (UTC)
+    pub max_daily_meters: u64,
+    pub min_duration_secs: u64,
+}
+
+/// Configuração de um clube novo, passada ao `create_club`
+///
+/// `usdc_per_km` é a taxa paga por km corrido, em unidades do USDC. As inscrições
+/// ficam abertas até `registration_close_timestamp` e só contam as corridas entre
+/// `start_timestamp` e `end_timestamp`. Com `max_members`, quem chega com o clube
+/// cheio vai para a fila de espera.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClubConfig {
+    pub usdc_per_km: i128,
+    pub withdrawal_rule: WithdrawalRule,
+    pub surplus_rule: SurplusRule,
+    pub start_timestamp: u64,
+    pub registration_close_timestamp: u64,
+    pub end_timestamp: u64,
+    pub limits: RunLimits,
+    pub max_members: Option<u32>,
+}
+
+/// Estado do clube no seu ciclo de vida
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClubStatus {
+    Draft,
+    Active,
+    Ended,
+    Settled,
+    Removed,
 }
 
 #[contracttype]
@@ -14,13 +130,246 @@ pub struct Club {
     pub id: u64,
     pub name: String,
     pub organizer: Address,
-    pub members: Vec<Address>,
+    /// Novo organizador proposto, que ainda precisa aceitar
+    pub pending_organizer: Option<Address>,
+    pub member_count: u32,
+    /// Vagas do clube; com o clube cheio, quem chega entra na fila de espera
+    pub max_members: Option<u32>,
     pub usdc_deposited: i128,
     pub usdc_per_km: i128,
     pub withdrawal_rule: WithdrawalRule,
+    pub surplus_rule: SurplusRule,
+    pub removed_km_policy: RemovedKmPolicy,
+    pub membership_policy: MembershipPolicy,
+    /// Início da temporada atual; corridas anteriores não contam
+    pub start_timestamp: u64,
+    /// Fim das inscrições da temporada atual
+    pub registration_close_timestamp: u64,
+    /// Fim da temporada atual
     pub month_end_timestamp: u64,
+    /// Temporada atual, a partir de 1
+    pub season: u32,
+    /// Prazo para resgatar depois do fim do período; depois dele o organizador
+    /// recolhe o que sobrou
+    pub claim_window_secs: u64,
     pub is_active: bool,
     pub token_address: Address,
+    pub created_at: u64,
+    pub status: ClubStatus,
+    pub limits: RunLimits,
+}
+
+/// Escopos que o organizador pode delegar a um co-organizador
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClubPermission {
+    /// Política de entrada, pedidos, convites, remoção de membros e KM de quem sai
+    Membership,
+    /// Ativar o clube, depositar USDC e devolver a sobra ao organizador
+    Funding,
+    /// Prazo de resgate, recolhimento do USDC não resgatado e novas temporadas
+    Settlement,
+}
+
+/// Início, fim e tamanho de uma fila de endereços de um clube
+///
+/// As posições `head..tail` podem ter lugares vazios deixados por quem saiu do meio
+/// da fila; `len` conta só os ocupados. `head` e `last` são a primeira e a última
+/// posição ocupada.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueueBounds {
+    pub head: u32,
+    pub last: u32,
+    pub tail: u32,
+    pub len: u32,
+}
+
+/// Endereço numa posição da fila, ligado às posições ocupadas vizinhas
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueueEntry {
+    pub user: Address,
+    pub prev: Option<u32>,
+    pub next: Option<u32>,
+}
+
+/// Co-organizador de um clube e os escopos que ele pode usar
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CoOrganizer {
+    pub address: Address,
+    pub permissions: Vec<ClubPermission>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MemberRole {
+    Member,
+    Organizer,
+}
+
+/// Registro de um membro, salvo em `DataKey::Member(club_id, address)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Member {
+    pub joined_at: u64,
+    pub role: MemberRole,
+    /// Posição do membro em `DataKey::MemberAt`, usada na paginação
+    pub index: u32,
+}
+
+/// Corrida atestada por um oráculo de atividade
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RunAttestation {
+    pub user: Address,
+    pub club_id: u64,
+    pub run_id: BytesN<32>,
+    pub distance_meters: u64,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub nonce: u64,
+}
+
+/// Corrida registrada no histórico do corredor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Run {
+    pub run_id: BytesN<32>,
+    pub distance_meters: u64,
+    /// KM creditado pela corrida, em unidades do token (7 casas decimais)
+    pub km: i128,
+    /// Temporada em que a corrida foi creditada
+    pub season: u32,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    pub recorded_at: u64,
+}
+
+/// Temporada de um clube, salva em `DataKey::Season(club_id, season)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub season: u32,
+    pub start_timestamp: u64,
+    pub end_timestamp: u64,
+    /// USDC herdado da temporada anterior
+    pub rolled_over: i128,
+}
+
+/// Fechamento de uma temporada, salvo em `DataKey::Settlement(club_id, season)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settlement {
+    pub settled_at: u64,
+    /// USDC do pool no momento do fechamento
+    pub pool: i128,
+    /// KM dos membros elegíveis, em unidades do token
+    pub total_km: i128,
+    /// Membros com KM no fechamento
+    pub eligible_members: u32,
+    /// Soma dos valores fixados para os membros
+    pub total_payout: i128,
+    /// Quanto desses valores já foi resgatado
+    pub claimed: i128,
+    /// Sobra do arredondamento distribuída entre os membros, incluída em `total_payout`
+    pub dust: i128,
+    /// Último instante em que os membros podem resgatar
+    pub claim_deadline: u64,
+    /// USDC recolhido pelo organizador depois do prazo
+    pub swept: i128,
+}
+
+/// Etapa de um fechamento que ainda está em andamento
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SettlementPhase {
+    /// Soma os KM e os totais que a regra de resgate precisa
+    Totals,
+    /// Fixa o valor de cada membro com KM
+    Payouts,
+    /// Distribui a poeira do arredondamento
+    Dust,
+}
+
+/// Totais dos KM dos membros que a regra de resgate precisa
+///
+/// O fechamento soma os seus na etapa `Totals`; os da temporada atual também são
+/// mantidos a cada corrida, entrada e saída em `DataKey::KmTotals(club_id, season)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KmTotals {
+    pub total_km: i128,
+    /// Membros com KM
+    pub eligible_members: u32,
+    /// Soma das faixas alcançadas, na regra `Tiered`
+    pub tier_demand: i128,
+    /// Membros que alcançaram o mínimo, na regra `Threshold`
+    pub qualified: u32,
+    /// Maiores KM e quantos membros têm cada um, do maior para o menor, na regra `TopN`
+    pub podium: Vec<(i128, u32)>,
+}
+
+/// Fechamento em andamento, salvo em `DataKey::SettlementDraft(club_id, season)`
+/// entre as chamadas de `settle_club`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SettlementDraft {
+    pub phase: SettlementPhase,
+    /// Próxima posição da lista de membros na etapa atual
+    pub cursor: u32,
+    /// USDC do pool quando o fechamento começou
+    pub pool: i128,
+    pub totals: KmTotals,
+    pub total_payout: i128,
+    /// Poeira já distribuída
+    pub dust: i128,
+    /// Poeira que ainda falta distribuir
+    pub dust_left: i128,
+}
+
+/// Valor fixado para um membro no fechamento, salvo em
+/// `DataKey::Payout(club_id, season, address)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payout {
+    /// KM do membro no fechamento, em unidades do token
+    pub km: i128,
+    pub amount: i128,
+    pub claimed: bool,
+}
+
+/// Entrada do índice `DataKey::UserClubs`, com o papel do usuário no clube
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserClub {
+    pub club_id: u64,
+    pub is_member: bool,
+    pub is_organizer: bool,
+}
+
+/// Resumo de um clube para listagens
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClubSummary {
+    pub id: u64,
+    pub name: String,
+    pub organizer: Address,
+    pub member_count: u32,
+    pub usdc_deposited: i128,
+    pub month_end_timestamp: u64,
+    pub is_active: bool,
+    pub status: ClubStatus,
+}
+
+/// Clube de um usuário com os papéis que ele ocupa
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserClubSummary {
+    pub club: ClubSummary,
+    pub is_member: bool,
+    pub is_organizer: bool,
 }
 
 #[contracttype]
@@ -28,42 +377,117 @@ pub struct Club {
 pub enum DataKey {
     Club(u64),
     ClubCounter,
-    UserKmTokens(Address, u64),
+    UserKmTokens(Address, u64, u32),
     UserClubs(Address),
+    UsdcToken,
+    TokenWasmHash,
+    Admin,
+    PlatformFee,
+    SchemaVersion,
+    MigrationCursor,
+    Member(u64, Address),
+    MemberAt(u64, u32),
+    ClubsByStatus(ClubStatus, u32),
+    ClubStatusCount(ClubStatus),
+    ClubStatusIndex(u64),
+    Oracles,
+    ClubOracles(u64),
+    ClubRecorders(u64),
+    CoOrganizers(u64),
+    JoinRequestQueue(u64),
+    JoinRequestAt(u64, u32),
+    JoinRequest(u64, Address),
+    WaitlistQueue(u64),
+    WaitlistAt(u64, u32),
+    Waitlisted(u64, Address),
+    Invite(u64, BytesN<32>),
+    AttestationNonce(Address, u64),
+    Run(u64, Address, u32),
+    RunCount(u64, Address),
+    RunId(u64, BytesN<32>),
+    DailyMeters(u64, Address, u64),
+    Settlement(u64, u32),
+    SettlementDraft(u64, u32),
+    KmTotals(u64, u32),
+    Payout(u64, u32, Address),
+    Season(u64, u32),
 }
 
+/// Taxa máxima da plataforma (100% em basis points)
+pub const MAX_PLATFORM_FEE_BPS: u32 = 10_000;
+
 #[contract]
 pub struct RunClubContract;
 
 #[contractimpl]
 impl RunClubContract {
-    pub fn initialize(env: Env) {}
-
-    pub fn get_club(env: Env, club_id: u64) -> Club;
-
-    pub fn activate(env: Env, club_id: u64, organizer: Address);
+    /// Configura o contrato na implantação com o admin, o USDC, o wasm do token KM
+    /// dos clubes e a taxa da plataforma em basis points
+    ///
+    /// Roda na mesma transação que implanta o contrato, então ninguém consegue
+    /// configurá-lo antes de quem o implantou.
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        usdc_token: Address,
+        km_token_wasm_hash: BytesN<32>,
+        platform_fee_bps: u32,
+    ) -> Result<(), RunClubError> {
+        if platform_fee_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(RunClubError::InvalidPlatformFee);
+        }
 
-    pub fn get_active_clubs(env: Env) -> Vec<u64>;
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::UsdcToken, &usdc_token);
+        token_factory::write_token_wasm_hash(&env, &km_token_wasm_hash);
+        env.storage()
+            .instance()
+            .set(&DataKey::PlatformFee, &platform_fee_bps);
+        upgrade::write_schema_version(&env, upgrade::SCHEMA_VERSION);
+        ttl::bump_instance(&env);
+        Ok(())
+    }
 
-    pub fn add_member(env: Env, club_id: u64, member: Address);
+    /// Endereço do admin do contrato
+    pub fn get_admin(env: Env) -> Result<Address, RunClubError> {
+        Self::admin(&env)
+    }
 
-    pub fn get_members(env: Env, club_id: u64) -> Vec<Address>;
+    /// Taxa da plataforma em basis points
+    pub fn get_platform_fee(env: Env) -> Result<u32, RunClubError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFee)
+            .ok_or(RunClubError::NotInitialized)
+    }
 
-    pub fn deposit_usdc(env: Env, club_id: u64, organizer: Address, amount: i128);
-
-    pub fn add_km_tokens(env: Env, club_id: u64, user: Address, km_amount: i128);
-
-    pub fn get_user_km_tokens(env: Env, user: Address, club_id: u64) -> i128;
-
-    pub fn is_club_period_ended(env: Env, club_id: u64) -> bool;
-
-    pub fn get_total_km_tokens(env: Env, club_id: u64) -> i128;
-
-    pub fn calculate_usdc_reward(env: Env, club_id: u64, user: Address) -> i128;
+    /// Endereço do token USDC configurado
+    pub fn get_usdc_token(env: Env) -> Result<Address, RunClubError> {
+        Self::usdc_token(&env)
+    }
+}
 
-    pub fn redeem_usdc(env: Env, club_id: u64, user: Address, destination: Address) -> i128;
+impl RunClubContract {
+    /// Exige a assinatura do admin configurado na implantação
+    pub(crate) fn require_admin(env: &Env) -> Result<Address, RunClubError> {
+        let admin = Self::admin(env)?;
+        admin.require_auth();
+        Ok(admin)
+    }
 
-    pub fn get_redemption_info(env: Env, club_id: u64, user: Address) -> (i128, i128, bool);
+    pub(crate) fn admin(env: &Env) -> Result<Address, RunClubError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(RunClubError::NotInitialized)
+    }
 
-    pub fn get_user_clubs(env: Env, user: Address) -> Vec<u64>;
+    pub(crate) fn usdc_token(env: &Env) -> Result<Address, RunClubError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::UsdcToken)
+            .ok_or(RunClubError::NotInitialized)
+    }
 }
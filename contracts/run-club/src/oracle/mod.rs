@@ -0,0 +1,200 @@
+//! Módulo de oráculos de atividade
+//!
+//! Este módulo mantém as chaves ed25519 dos oráculos confiáveis, do
+//! contrato inteiro ou de cada clube, e verifica as atestações de
+//! corrida assinadas por eles antes de creditar KM.
+
+use soroban_sdk::{contractimpl, symbol_short, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+use crate::ttl;
+use crate::{
+    Club, DataKey, RunAttestation, RunClubContract, RunClubContractArgs, RunClubContractClient,
+    RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Registra um oráculo aceito por todos os clubes (apenas admin)
+    pub fn add_oracle(env: Env, oracle_key: BytesN<32>) -> Result<(), RunClubError> {
+        Self::require_admin(&env)?;
+
+        let mut oracles = global_oracles(&env);
+        if oracles.contains(&oracle_key) {
+            return Err(RunClubError::OracleAlreadyExists);
+        }
+        oracles.push_back(oracle_key.clone());
+        env.storage().instance().set(&DataKey::Oracles, &oracles);
+
+        env.events()
+            .publish((symbol_short!("orc_add"),), oracle_key);
+
+        Ok(())
+    }
+
+    /// Remove um oráculo global (apenas admin)
+    pub fn remove_oracle(env: Env, oracle_key: BytesN<32>) -> Result<(), RunClubError> {
+        Self::require_admin(&env)?;
+
+        let mut oracles = global_oracles(&env);
+        let index = oracles
+            .first_index_of(&oracle_key)
+            .ok_or(RunClubError::OracleNotFound)?;
+        oracles.remove(index);
+        env.storage().instance().set(&DataKey::Oracles, &oracles);
+
+        env.events()
+            .publish((symbol_short!("orc_del"),), oracle_key);
+
+        Ok(())
+    }
+
+    /// Registra um oráculo próprio do clube (apenas organizador)
+    ///
+    /// Um clube com oráculos próprios deixa de aceitar os oráculos globais.
+    pub fn add_club_oracle(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        oracle_key: BytesN<32>,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        let mut oracles = club_oracles(&env, club_id);
+        if oracles.contains(&oracle_key) {
+            return Err(RunClubError::OracleAlreadyExists);
+        }
+        oracles.push_back(oracle_key.clone());
+        write_club_oracles(&env, club_id, &oracles);
+
+        env.events()
+            .publish((symbol_short!("orc_add"), club_id), oracle_key);
+
+        Ok(())
+    }
+
+    /// Remove um oráculo próprio do clube (apenas organizador)
+    pub fn remove_club_oracle(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        oracle_key: BytesN<32>,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let club = Self::load_club(&env, club_id)?;
+        Self::require_organizer(&club, &organizer)?;
+
+        let mut oracles = club_oracles(&env, club_id);
+        let index = oracles
+            .first_index_of(&oracle_key)
+            .ok_or(RunClubError::OracleNotFound)?;
+        oracles.remove(index);
+        write_club_oracles(&env, club_id, &oracles);
+
+        env.events()
+            .publish((symbol_short!("orc_del"), club_id), oracle_key);
+
+        Ok(())
+    }
+
+    /// Oráculos globais
+    pub fn get_oracles(env: Env) -> Result<Vec<BytesN<32>>, RunClubError> {
+        Ok(global_oracles(&env))
+    }
+
+    /// Oráculos próprios de um clube
+    pub fn get_club_oracles(env: Env, club_id: u64) -> Result<Vec<BytesN<32>>, RunClubError> {
+        Self::load_club(&env, club_id)?;
+        Ok(club_oracles(&env, club_id))
+    }
+}
+
+impl RunClubContract {
+    /// Verifica uma atestação de corrida assinada por um oráculo e consome o seu nonce
+    ///
+    /// A assinatura cobre `(endereço deste contrato, atestação)` em XDR, para que
+    /// uma atestação não possa ser reaproveitada em outro deploy. O `ed25519_verify`
+    /// não devolve erro: uma assinatura inválida aborta a chamada com o erro do host
+    /// `Error(Crypto, InvalidInput)`, e não com um `RunClubError`.
+    pub(crate) fn verify_attestation(
+        env: &Env,
+        club: &Club,
+        attestation: &RunAttestation,
+        oracle_key: &BytesN<32>,
+        signature: &BytesN<64>,
+    ) -> Result<(), RunClubError> {
+        Self::check_attestation(env, club, attestation)?;
+
+        let mut trusted = club_oracles(env, club.id);
+        if trusted.is_empty() {
+            trusted = global_oracles(env);
+        }
+        if !trusted.contains(oracle_key) {
+            return Err(RunClubError::UnknownOracle);
+        }
+
+        let message = (env.current_contract_address(), attestation.clone()).to_xdr(env);
+        env.crypto().ed25519_verify(oracle_key, &message, signature);
+
+        Self::consume_nonce(env, attestation);
+        Ok(())
+    }
+
+    /// Confere o clube, as datas e o nonce de uma atestação, sem olhar a assinatura
+    pub(crate) fn check_attestation(
+        env: &Env,
+        club: &Club,
+        attestation: &RunAttestation,
+    ) -> Result<(), RunClubError> {
+        if attestation.club_id != club.id
+            || attestation.end_timestamp < attestation.start_timestamp
+            || attestation.end_timestamp > env.ledger().timestamp()
+        {
+            return Err(RunClubError::InvalidAttestation);
+        }
+
+        let nonce_key = DataKey::AttestationNonce(attestation.user.clone(), attestation.nonce);
+        if env.storage().persistent().has(&nonce_key) {
+            return Err(RunClubError::NonceAlreadyUsed);
+        }
+        Ok(())
+    }
+
+    /// Marca o nonce de uma atestação como usado
+    pub(crate) fn consume_nonce(env: &Env, attestation: &RunAttestation) {
+        let nonce_key = DataKey::AttestationNonce(attestation.user.clone(), attestation.nonce);
+        env.storage().persistent().set(&nonce_key, &true);
+        ttl::bump(env, &nonce_key);
+    }
+}
+
+fn global_oracles(env: &Env) -> Vec<BytesN<32>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Oracles)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn club_oracles(env: &Env, club_id: u64) -> Vec<BytesN<32>> {
+    let key = DataKey::ClubOracles(club_id);
+    let oracles = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+    ttl::bump(env, &key);
+    oracles
+}
+
+fn write_club_oracles(env: &Env, club_id: u64, oracles: &Vec<BytesN<32>>) {
+    let key = DataKey::ClubOracles(club_id);
+    if oracles.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, oracles);
+        ttl::bump(env, &key);
+    }
+}
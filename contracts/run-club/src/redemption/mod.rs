@@ -0,0 +1,10 @@
+//! Módulo para resgate de tokens KM por USDC
+//!
+//! Este módulo contém o cálculo das recompensas de acordo com a
+//! regra de distribuição do clube e a execução do resgate ao
+//! final do período.
+
+pub mod redemption_operations;
+pub mod redemption_queries;
+pub mod reward_rules;
+pub mod settlement;
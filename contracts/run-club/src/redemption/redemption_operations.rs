@@ -0,0 +1,105 @@
+use soroban_sdk::{contractimpl, token, Address, Env};
+
+use crate::{
+    ClubPermission, DataKey, RunClubContract, RunClubContractArgs, RunClubContractClient,
+    RunClubError,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Resgata o valor fixado para o membro no fechamento, queimando seus tokens KM
+    ///
+    /// Resgatam os membros e quem saiu ou foi removido depois do fechamento, que
+    /// mantém o valor fixado; KM congelados de quem foi removido antes voltam a
+    /// valer se ele entrar de novo.
+    pub fn redeem_usdc(
+        env: Env,
+        club_id: u64,
+        user: Address,
+        destination: Address,
+    ) -> Result<i128, RunClubError> {
+        user.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        let mut settlement = Self::settle_if_needed(&env, &mut club)?;
+        if Self::payout(&env, &club, &user).is_none() {
+            Self::require_member(&env, &club, &user)?;
+        }
+
+        if env.ledger().timestamp() > settlement.claim_deadline {
+            return Err(RunClubError::ClaimWindowClosed);
+        }
+
+        let mut payout = Self::payout(&env, &club, &user)
+            .filter(|payout| !payout.claimed)
+            .ok_or(RunClubError::NoKmTokens)?;
+        let reward = payout.amount;
+
+        // Queimar os tokens KM do fechamento antes de pagar
+        let km_token = token::Client::new(&env, &club.token_address);
+        if km_token.balance(&user) < payout.km {
+            return Err(RunClubError::InsufficientBalance);
+        }
+        km_token.burn(&user, &payout.km);
+        if Self::member(&env, club_id, &user).is_some() {
+            Self::update_km_totals(&env, &club, Self::user_km(&env, &user, &club), 0);
+        }
+        env.storage().persistent().set(
+            &DataKey::UserKmTokens(user.clone(), club_id, club.season),
+            &0i128,
+        );
+
+        payout.claimed = true;
+        Self::write_payout(&env, &club, &user, &payout);
+        settlement.claimed += reward;
+        Self::write_settlement(&env, &club, &settlement);
+
+        club.usdc_deposited -= reward;
+        Self::save_club(&env, &mut club);
+
+        if reward > 0 {
+            let usdc = token::Client::new(&env, &Self::usdc_token(&env)?);
+            usdc.transfer(&env.current_contract_address(), &destination, &reward);
+        }
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("redeem"),),
+            (club_id, user, destination, reward),
+        );
+
+        Ok(reward)
+    }
+
+    /// Devolve ao organizador o USDC que a taxa `usdc_per_km` não vai consumir
+    /// (organizador ou co-organizador com `Funding`; só depois do fim do período,
+    /// fechando o clube se ninguém o fechou ainda)
+    pub fn withdraw_surplus(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+    ) -> Result<i128, RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Funding)?;
+        Self::settle_if_needed(&env, &mut club)?;
+
+        let surplus = Self::get_club_surplus(env.clone(), club_id)?;
+        if surplus <= 0 {
+            return Err(RunClubError::NoSurplus);
+        }
+
+        club.usdc_deposited -= surplus;
+        Self::save_club(&env, &mut club);
+
+        let usdc = token::Client::new(&env, &Self::usdc_token(&env)?);
+        usdc.transfer(&env.current_contract_address(), &club.organizer, &surplus);
+
+        env.events().publish(
+            (soroban_sdk::symbol_short!("surplus"),),
+            (club_id, organizer, surplus),
+        );
+
+        Ok(surplus)
+    }
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contractimpl, Address, Env};
+
+use super::reward_rules::rate_payout;
+use crate::{
+    RunClubContract, RunClubContractArgs, RunClubContractClient, RunClubError, SurplusRule,
+    WithdrawalRule,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Calcula a recompensa USDC para um usuário baseado na regra de distribuição
+    ///
+    /// Depois do fechamento é o valor fixado para o membro (0 se já resgatou);
+    /// antes dele, uma prévia com os KM e o pool atuais, usando os totais mantidos
+    /// a cada corrida. As contas são feitas em ponto fixo sobre as unidades do token
+    /// KM e arredondam para baixo; a poeira do arredondamento só é distribuída no
+    /// fechamento e fica de fora da prévia.
+    pub fn calculate_usdc_reward(
+        env: Env,
+        club_id: u64,
+        user: Address,
+    ) -> Result<i128, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+
+        if Self::settlement(&env, &club).is_some() {
+            return Ok(Self::payout(&env, &club, &user)
+                .filter(|payout| !payout.claimed)
+                .map_or(0, |payout| payout.amount));
+        }
+
+        if Self::member(&env, club_id, &user).is_none() {
+            return Ok(0);
+        }
+
+        if Self::user_km(&env, &user, &club) <= 0 || club.usdc_deposited <= 0 {
+            return Ok(0);
+        }
+
+        Ok(Self::preview_payout(&env, &club, &user))
+    }
+
+    /// USDC do pool que não será pago aos corredores e pode voltar ao organizador
+    ///
+    /// Só existe na regra `Unlimited` com `SurplusRule::ReturnToOrganizer`. Antes do
+    /// fechamento é o pool menos km × `usdc_per_km` do total; depois, o pool menos os
+    /// valores fixados que ainda não foram resgatados, que também guarda o
+    /// arredondamento de cada membro.
+    pub fn get_club_surplus(env: Env, club_id: u64) -> Result<i128, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        if club.withdrawal_rule != WithdrawalRule::Unlimited
+            || club.surplus_rule != SurplusRule::ReturnToOrganizer
+        {
+            return Ok(0);
+        }
+
+        let owed = match Self::settlement(&env, &club) {
+            Some(settlement) => settlement.total_payout - settlement.claimed,
+            None => rate_payout(&club, Self::get_total_km_tokens(env, club_id)?),
+        };
+        Ok((club.usdc_deposited - owed).max(0))
+    }
+
+    /// Obtém informações de resgate para um usuário
+    pub fn get_redemption_info(
+        env: Env,
+        club_id: u64,
+        user: Address,
+    ) -> Result<(i128, i128, bool), RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        let km_tokens = Self::user_km(&env, &user, &club);
+        let usdc_reward = Self::calculate_usdc_reward(env.clone(), club_id, user)?;
+        let period_ended = Self::is_club_period_ended(env, club_id)?;
+        Ok((km_tokens, usdc_reward, period_ended))
+    }
+}
+
+/// `amount * numerator / denominator` arredondado para baixo
+///
+/// Com USDC e KM em 7 casas decimais o produto cabe folgado em `i128`
+/// (até ~10^38): 10^12 USDC vezes 10^9 km ainda fica em 10^35.
+pub(crate) fn mul_div_floor(amount: i128, numerator: i128, denominator: i128) -> i128 {
+    if denominator <= 0 {
+        return 0;
+    }
+    (amount * numerator).div_euclid(denominator)
+}
@@ -0,0 +1,244 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use super::redemption_queries::mul_div_floor;
+use crate::{
+    Club, KmTotals, RewardTier, RunClubContract, SettlementDraft, SettlementPhase, SurplusRule,
+    WithdrawalRule, KM_UNIT,
+};
+
+/// Denominador das fatias do pódio (100% em basis points)
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+impl RunClubContract {
+    /// Fechamento vazio, começando pela soma dos KM com o pool atual
+    pub(crate) fn new_draft(env: &Env, club: &Club) -> SettlementDraft {
+        SettlementDraft {
+            phase: SettlementPhase::Totals,
+            cursor: 0,
+            pool: club.usdc_deposited,
+            totals: empty_totals(env),
+            total_payout: 0,
+            dust: 0,
+            dust_left: 0,
+        }
+    }
+
+    /// Valor que o fechamento fixaria agora para `user`, pelos totais mantidos da
+    /// temporada atual (só para consultas)
+    ///
+    /// Não inclui a poeira, que depende da ordem da lista de membros e só é
+    /// distribuída no fechamento. Na regra `TopN`, quem subiria no pódio com a saída
+    /// de um membro só aparece nele no fechamento.
+    pub(crate) fn preview_payout(env: &Env, club: &Club, user: &Address) -> i128 {
+        let draft = SettlementDraft {
+            totals: Self::km_totals(env, club),
+            ..Self::new_draft(env, club)
+        };
+        payout_amount(club, &draft, Self::user_km(env, user, club))
+    }
+}
+
+/// Totais sem nenhum KM
+pub(crate) fn empty_totals(env: &Env) -> KmTotals {
+    KmTotals {
+        total_km: 0,
+        eligible_members: 0,
+        tier_demand: 0,
+        qualified: 0,
+        podium: Vec::new(env),
+    }
+}
+
+/// Soma os KM de um membro aos totais que a regra de resgate precisa
+///
+/// Membros sem KM não entram na conta nem diluem o pool.
+pub(crate) fn tally(rule: &WithdrawalRule, totals: &mut KmTotals, km: i128) {
+    if km <= 0 {
+        return;
+    }
+    totals.total_km += km;
+    totals.eligible_members += 1;
+
+    match rule {
+        WithdrawalRule::Tiered(tiers) => totals.tier_demand += tier_reward(tiers, km),
+        WithdrawalRule::Threshold(min_km) if km >= *min_km => totals.qualified += 1,
+        WithdrawalRule::TopN(places) => tally_podium(&mut totals.podium, places.len(), km),
+        _ => {}
+    }
+}
+
+/// Tira os KM de um membro dos totais, desfazendo o `tally`
+pub(crate) fn untally(rule: &WithdrawalRule, totals: &mut KmTotals, km: i128) {
+    if km <= 0 {
+        return;
+    }
+    totals.total_km -= km;
+    totals.eligible_members -= 1;
+
+    match rule {
+        WithdrawalRule::Tiered(tiers) => totals.tier_demand -= tier_reward(tiers, km),
+        WithdrawalRule::Threshold(min_km) if km >= *min_km => totals.qualified -= 1,
+        WithdrawalRule::TopN(_) => untally_podium(&mut totals.podium, km),
+        _ => {}
+    }
+}
+
+/// Valor de um membro com `km` pela regra de resgate, arredondado para baixo
+pub(crate) fn payout_amount(club: &Club, draft: &SettlementDraft, km: i128) -> i128 {
+    if km <= 0 {
+        return 0;
+    }
+    let pool = draft.pool;
+    match &club.withdrawal_rule {
+        WithdrawalRule::Equal => mul_div_floor(pool, 1, draft.totals.eligible_members as i128),
+        WithdrawalRule::Unlimited => {
+            // km × `usdc_per_km`, limitado à fatia proporcional do pool
+            let share = mul_div_floor(pool, km, draft.totals.total_km);
+            match club.surplus_rule {
+                SurplusRule::ReturnToOrganizer => share.min(rate_payout(club, km)),
+                SurplusRule::ProRata => share,
+            }
+        }
+        WithdrawalRule::Tiered(tiers) => {
+            // Faixas reduzidas na proporção quando somam mais que o pool
+            let reward = tier_reward(tiers, km);
+            if draft.totals.tier_demand <= pool {
+                reward
+            } else {
+                mul_div_floor(pool, reward, draft.totals.tier_demand)
+            }
+        }
+        WithdrawalRule::TopN(places) => podium_reward(&draft.totals.podium, places, pool, km),
+        WithdrawalRule::Threshold(min_km) => {
+            if km >= *min_km {
+                mul_div_floor(pool, 1, draft.totals.qualified as i128)
+            } else {
+                0
+            }
+        }
+    }
+}
+
+/// Quanto a regra distribuiria sem arredondamento
+///
+/// A diferença para a soma dos valores arredondados é a poeira, que vai uma unidade
+/// por membro para quem recebe algo, na ordem da lista de membros. O que a regra
+/// não distribui fica no pool.
+///
+/// Na regra `Unlimited` com `SurplusRule::ReturnToOrganizer`, quando km ×
+/// `usdc_per_km` cabe no pool, cada membro recebe só o próprio valor arredondado:
+/// a poeira passaria alguém da taxa, então o arredondamento fica no pool como sobra
+/// do organizador.
+pub(crate) fn payout_target(club: &Club, draft: &SettlementDraft) -> i128 {
+    let pool = draft.pool;
+    match &club.withdrawal_rule {
+        _ if draft.totals.eligible_members == 0 => 0,
+        WithdrawalRule::Equal => pool,
+        WithdrawalRule::Unlimited => match club.surplus_rule {
+            SurplusRule::ReturnToOrganizer if rate_payout(club, draft.totals.total_km) < pool => {
+                draft.total_payout
+            }
+            SurplusRule::ReturnToOrganizer | SurplusRule::ProRata => pool,
+        },
+        WithdrawalRule::Tiered(_) => pool.min(draft.totals.tier_demand),
+        WithdrawalRule::TopN(places) => {
+            let mut bps = 0u32;
+            for place in 0..places.len().min(draft.totals.eligible_members) {
+                bps += places.get_unchecked(place);
+            }
+            mul_div_floor(pool, bps as i128, BPS_DENOMINATOR as i128)
+        }
+        WithdrawalRule::Threshold(_) if draft.totals.qualified == 0 => 0,
+        WithdrawalRule::Threshold(_) => pool,
+    }
+}
+
+/// Pagamento de `km` unidades do token KM pela taxa `usdc_per_km` do clube
+pub(crate) fn rate_payout(club: &Club, km: i128) -> i128 {
+    mul_div_floor(km, club.usdc_per_km, KM_UNIT)
+}
+
+/// Valor da maior faixa cujo mínimo o corredor alcançou (0 abaixo da primeira)
+fn tier_reward(tiers: &Vec<RewardTier>, km: i128) -> i128 {
+    let mut reward = 0;
+    for tier in tiers.iter() {
+        if km >= tier.min_km {
+            reward = tier.reward;
+        }
+    }
+    reward
+}
+
+/// Conta `km` no pódio, guardando só os KM que ainda podem ocupar uma das `places`
+/// colocações
+fn tally_podium(podium: &mut Vec<(i128, u32)>, places: u32, km: i128) {
+    let mut index = 0;
+    while index < podium.len() {
+        let (top, count) = podium.get_unchecked(index);
+        if top == km {
+            podium.set(index, (top, count + 1));
+            break;
+        }
+        if top < km {
+            podium.insert(index, (km, 1));
+            break;
+        }
+        index += 1;
+    }
+    if index == podium.len() {
+        podium.push_back((km, 1));
+    }
+
+    // Quem já tem `places` membros à frente não chega ao pódio
+    let mut ahead = 0u32;
+    let mut keep = 0u32;
+    for (_, count) in podium.iter() {
+        if ahead >= places {
+            break;
+        }
+        ahead += count;
+        keep += 1;
+    }
+    while podium.len() > keep {
+        podium.pop_back();
+    }
+}
+
+/// Tira um membro com `km` do pódio, se ele estava entre as colocações guardadas
+fn untally_podium(podium: &mut Vec<(i128, u32)>, km: i128) {
+    let Some(index) = podium.iter().position(|(top, _)| top == km) else {
+        return;
+    };
+    let index = index as u32;
+    let (top, count) = podium.get_unchecked(index);
+    if count > 1 {
+        podium.set(index, (top, count - 1));
+    } else {
+        podium.remove(index);
+    }
+}
+
+/// Fatia do pódio pela colocação em KM
+///
+/// Membros empatados dividem as fatias das colocações que ocupam; fatias sem
+/// ninguém (ou abaixo de 100%) ficam no pool.
+fn podium_reward(podium: &Vec<(i128, u32)>, places: &Vec<u32>, pool: i128, km: i128) -> i128 {
+    let mut ahead = 0u32;
+    let mut tied = 0u32;
+    for (top, count) in podium.iter() {
+        if top > km {
+            ahead += count;
+        } else if top == km {
+            tied = count;
+        }
+    }
+    if tied == 0 {
+        return 0;
+    }
+
+    let mut bps = 0u32;
+    for place in ahead..(ahead + tied).min(places.len()) {
+        bps += places.get_unchecked(place);
+    }
+    mul_div_floor(pool, bps as i128, BPS_DENOMINATOR as i128 * tied as i128)
+}
@@ -0,0 +1,342 @@
+use soroban_sdk::{contractimpl, symbol_short, token, Address, Env};
+
+use super::reward_rules::{payout_amount, payout_target, tally};
+use crate::ttl;
+use crate::{
+    Club, ClubPermission, ClubStatus, DataKey, Payout, RunClubContract, RunClubContractArgs,
+    RunClubContractClient, RunClubError, Settlement, SettlementDraft, SettlementPhase,
+    MAX_PAGE_LIMIT,
+};
+
+#[contractimpl]
+impl RunClubContract {
+    /// Fecha o período do clube e fixa o valor de cada membro (qualquer um pode chamar)
+    ///
+    /// Cada chamada percorre no máximo `limit` membros (até `MAX_PAGE_LIMIT`) e
+    /// devolve o fechamento quando ele termina; até lá o progresso fica salvo e
+    /// ninguém sai do clube. Só os membros com KM entram no fechamento. Depois dele a
+    /// ordem dos resgates não muda o valor de ninguém; em clubes pequenos o primeiro
+    /// resgate fecha o clube se ninguém tiver chamado esta função antes.
+    pub fn settle_club(
+        env: Env,
+        club_id: u64,
+        limit: u32,
+    ) -> Result<Option<Settlement>, RunClubError> {
+        let mut club = Self::load_club(&env, club_id)?;
+
+        if Self::settlement(&env, &club).is_some() {
+            return Err(RunClubError::ClubAlreadySettled);
+        }
+
+        if env.ledger().timestamp() <= club.month_end_timestamp {
+            return Err(RunClubError::ClubPeriodNotEnded);
+        }
+
+        Ok(Self::settle(&env, &mut club, limit.min(MAX_PAGE_LIMIT)))
+    }
+
+    /// Define o prazo de resgate depois do fim do período (organizador ou
+    /// co-organizador com `Settlement`, antes do fim do período)
+    ///
+    /// O prazo fica entre `MIN_CLAIM_WINDOW` (7 dias) e `MAX_CLAIM_WINDOW` (180 dias).
+    pub fn set_claim_window(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        claim_window_secs: u64,
+    ) -> Result<(), RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        Self::require_permission(&env, &club, &organizer, ClubPermission::Settlement)?;
+
+        if !(ttl::MIN_CLAIM_WINDOW..=ttl::MAX_CLAIM_WINDOW).contains(&claim_window_secs) {
+            return Err(RunClubError::InvalidDuration);
+        }
+
+        // Encurtar o prazo depois do fim do período tiraria o USDC dos membros
+        if env.ledger().timestamp() > club.month_end_timestamp {
+            return Err(RunClubError::ClubPeriodEnded);
+        }
+
+        club.claim_window_secs = claim_window_secs;
+        Self::save_club(&env, &mut club);
+
+        env.events().publish(
+            (symbol_short!("claim_win"),),
+            (club_id, organizer, claim_window_secs),
+        );
+
+        Ok(())
+    }
+
+    /// Envia ao organizador o USDC não resgatado depois do prazo de resgate
+    /// (organizador ou co-organizador com `Settlement`)
+    pub fn sweep_unclaimed(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+    ) -> Result<i128, RunClubError> {
+        organizer.require_auth();
+
+        let mut club = Self::load_club(&env, club_id)?;
+        let amount = Self::take_unclaimed(&env, &mut club, &organizer)?;
+        Self::save_club(&env, &mut club);
+
+        let usdc = token::Client::new(&env, &Self::usdc_token(&env)?);
+        usdc.transfer(&env.current_contract_address(), &club.organizer, &amount);
+
+        env.events()
+            .publish((symbol_short!("sweep"),), (club_id, organizer, amount));
+
+        Ok(amount)
+    }
+
+    /// Passa o USDC não resgatado depois do prazo para o pool de outro clube aberto
+    /// do mesmo organizador (com `Settlement` no clube de origem e `Funding` no destino)
+    pub fn rollover_unclaimed(
+        env: Env,
+        club_id: u64,
+        organizer: Address,
+        target_club_id: u64,
+    ) -> Result<i128, RunClubError> {
+        organizer.require_auth();
+
+        let mut target = Self::load_club(&env, target_club_id)?;
+        Self::require_permission(&env, &target, &organizer, ClubPermission::Funding)?;
+        if !matches!(target.status, ClubStatus::Draft | ClubStatus::Active)
+            || Self::is_expired(&env, &target)
+        {
+            return Err(RunClubError::ClubNotActive);
+        }
+
+        let mut club = Self::load_club(&env, club_id)?;
+        if target.organizer != club.organizer {
+            return Err(RunClubError::NotOrganizer);
+        }
+        let amount = Self::take_unclaimed(&env, &mut club, &organizer)?;
+        Self::save_club(&env, &mut club);
+
+        // Os fundos já estão no contrato: só mudam de pool
+        target.usdc_deposited += amount;
+        if target.status == ClubStatus::Draft {
+            Self::set_club_status(&env, &mut target, ClubStatus::Active);
+        }
+        Self::save_club(&env, &mut target);
+
+        env.events().publish(
+            (symbol_short!("rollover"),),
+            (club_id, target_club_id, amount),
+        );
+
+        Ok(amount)
+    }
+
+    /// Fechamento da temporada atual de um clube
+    pub fn get_settlement(env: Env, club_id: u64) -> Result<Settlement, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Self::settlement(&env, &club).ok_or(RunClubError::ClubNotSettled)
+    }
+
+    /// Valor fixado para um membro no fechamento da temporada atual
+    pub fn get_payout(env: Env, club_id: u64, user: Address) -> Result<Payout, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        Self::settlement(&env, &club).ok_or(RunClubError::ClubNotSettled)?;
+        Self::payout(&env, &club, &user).ok_or(RunClubError::NoKmTokens)
+    }
+}
+
+impl RunClubContract {
+    /// Fecha o clube se o período terminou e ninguém o fechou ainda
+    ///
+    /// Só fecha de uma vez o que cabe em `MAX_PAGE_LIMIT` membros percorridos;
+    /// clubes maiores precisam de `settle_club` antes.
+    pub(crate) fn settle_if_needed(env: &Env, club: &mut Club) -> Result<Settlement, RunClubError> {
+        if let Some(settlement) = Self::settlement(env, club) {
+            return Ok(settlement);
+        }
+
+        if env.ledger().timestamp() <= club.month_end_timestamp {
+            return Err(RunClubError::ClubPeriodNotEnded);
+        }
+
+        Self::settle(env, club, MAX_PAGE_LIMIT).ok_or(RunClubError::ClubNotSettled)
+    }
+
+    /// Avança o fechamento por até `budget` membros e, ao terminar, move o clube para
+    /// `Settled`
+    ///
+    /// Primeiro soma os KM, depois fixa o valor de cada membro com KM e por fim
+    /// distribui a poeira, cada etapa percorrendo a lista de membros.
+    pub(crate) fn settle(env: &Env, club: &mut Club, budget: u32) -> Option<Settlement> {
+        let mut draft =
+            Self::settlement_draft(env, club).unwrap_or_else(|| Self::new_draft(env, club));
+        let mut budget = budget;
+
+        loop {
+            if draft.cursor >= club.member_count
+                || draft.phase == SettlementPhase::Dust && draft.dust_left == 0
+            {
+                match draft.phase {
+                    SettlementPhase::Totals => draft.phase = SettlementPhase::Payouts,
+                    SettlementPhase::Payouts => {
+                        draft.phase = SettlementPhase::Dust;
+                        draft.dust_left = (payout_target(club, &draft) - draft.total_payout).max(0);
+                    }
+                    SettlementPhase::Dust => {
+                        return Some(Self::finish_settlement(env, club, &draft))
+                    }
+                }
+                draft.cursor = 0;
+                continue;
+            }
+            if budget == 0 {
+                Self::write_settlement_draft(env, club, &draft);
+                return None;
+            }
+
+            let member: Address = env
+                .storage()
+                .persistent()
+                .get(&DataKey::MemberAt(club.id, draft.cursor))
+                .unwrap();
+            match draft.phase {
+                SettlementPhase::Totals => {
+                    let km = Self::user_km(env, &member, club);
+                    tally(&club.withdrawal_rule, &mut draft.totals, km)
+                }
+                SettlementPhase::Payouts => {
+                    let km = Self::user_km(env, &member, club);
+                    if km > 0 {
+                        let amount = payout_amount(club, &draft, km);
+                        Self::write_payout(
+                            env,
+                            club,
+                            &member,
+                            &Payout {
+                                km,
+                                amount,
+                                claimed: false,
+                            },
+                        );
+                        draft.total_payout += amount;
+                    }
+                }
+                SettlementPhase::Dust => {
+                    if let Some(mut payout) =
+                        Self::payout(env, club, &member).filter(|payout| payout.amount > 0)
+                    {
+                        payout.amount += 1;
+                        Self::write_payout(env, club, &member, &payout);
+                        draft.total_payout += 1;
+                        draft.dust += 1;
+                        draft.dust_left -= 1;
+                    }
+                }
+            }
+            draft.cursor += 1;
+            budget -= 1;
+        }
+    }
+
+    /// Grava o fechamento terminado e move o clube para `Settled`
+    fn finish_settlement(env: &Env, club: &mut Club, draft: &SettlementDraft) -> Settlement {
+        let settlement = Settlement {
+            settled_at: env.ledger().timestamp(),
+            pool: draft.pool,
+            total_km: draft.totals.total_km,
+            eligible_members: draft.totals.eligible_members,
+            total_payout: draft.total_payout,
+            claimed: 0,
+            dust: draft.dust,
+            claim_deadline: club
+                .month_end_timestamp
+                .saturating_add(club.claim_window_secs),
+            swept: 0,
+        };
+        Self::write_settlement(env, club, &settlement);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::SettlementDraft(club.id, club.season));
+
+        Self::set_club_status(env, club, ClubStatus::Settled);
+        Self::save_club(env, club);
+
+        env.events().publish(
+            (symbol_short!("settle"),),
+            (
+                club.id,
+                settlement.total_payout,
+                settlement.eligible_members,
+                settlement.dust,
+            ),
+        );
+
+        settlement
+    }
+
+    /// Esvazia o pool de um clube fechado cujo prazo de resgate acabou
+    fn take_unclaimed(
+        env: &Env,
+        club: &mut Club,
+        organizer: &Address,
+    ) -> Result<i128, RunClubError> {
+        Self::require_permission(env, club, organizer, ClubPermission::Settlement)?;
+        let mut settlement = Self::settle_if_needed(env, club)?;
+
+        if env.ledger().timestamp() <= settlement.claim_deadline {
+            return Err(RunClubError::ClaimWindowOpen);
+        }
+
+        let amount = club.usdc_deposited;
+        if amount <= 0 {
+            return Err(RunClubError::NothingToSweep);
+        }
+
+        club.usdc_deposited = 0;
+        settlement.swept += amount;
+        Self::write_settlement(env, club, &settlement);
+        Ok(amount)
+    }
+
+    /// Fechamento da temporada atual do clube
+    pub(crate) fn settlement(env: &Env, club: &Club) -> Option<Settlement> {
+        let key = DataKey::Settlement(club.id, club.season);
+        let settlement = env.storage().persistent().get(&key);
+        ttl::bump(env, &key);
+        settlement
+    }
+
+    pub(crate) fn write_settlement(env: &Env, club: &Club, settlement: &Settlement) {
+        let key = DataKey::Settlement(club.id, club.season);
+        env.storage().persistent().set(&key, settlement);
+        ttl::bump(env, &key);
+    }
+
+    /// Fechamento da temporada atual que ainda está em andamento
+    pub(crate) fn settlement_draft(env: &Env, club: &Club) -> Option<SettlementDraft> {
+        let key = DataKey::SettlementDraft(club.id, club.season);
+        let draft = env.storage().persistent().get(&key);
+        ttl::bump(env, &key);
+        draft
+    }
+
+    fn write_settlement_draft(env: &Env, club: &Club, draft: &SettlementDraft) {
+        let key = DataKey::SettlementDraft(club.id, club.season);
+        env.storage().persistent().set(&key, draft);
+        ttl::bump(env, &key);
+    }
+
+    pub(crate) fn payout(env: &Env, club: &Club, user: &Address) -> Option<Payout> {
+        let key = DataKey::Payout(club.id, club.season, user.clone());
+        let payout = env.storage().persistent().get(&key);
+        ttl::bump(env, &key);
+        payout
+    }
+
+    pub(crate) fn write_payout(env: &Env, club: &Club, user: &Address, payout: &Payout) {
+        let key = DataKey::Payout(club.id, club.season, user.clone());
+        env.storage().persistent().set(&key, payout);
+        ttl::bump(env, &key);
+    }
+}
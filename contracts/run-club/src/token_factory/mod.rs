@@ -1,29 +1,93 @@
-use soroban_sdk::{contractclient, contractimpl, contracttype, Address, Env, String, BytesN};
-use soroban_token_contract::TokenClient;
+use soroban_sdk::{contractclient, contractimpl, symbol_short, Address, BytesN, Env, String};
 
-#[contracttype]
-pub enum DataKey {
-    TokenWasmHash,
+use crate::{DataKey, RunClubContract, RunClubContractArgs, RunClubContractClient, RunClubError};
+
+/// Casas decimais do token KM de cada clube
+pub const KM_TOKEN_DECIMALS: u32 = 7;
+
+/// Unidades do token KM em 1 km (10^7)
+pub const KM_UNIT: i128 = 10i128.pow(KM_TOKEN_DECIMALS);
+
+/// Unidades do token KM em 1 metro
+pub const KM_UNITS_PER_METER: i128 = KM_UNIT / 1000;
+
+/// Converte metros em unidades do token KM, sem arredondamento
+pub fn km_from_meters(meters: u64) -> i128 {
+    meters as i128 * KM_UNITS_PER_METER
 }
 
+#[allow(dead_code)]
 #[contractclient(name = "TokenClient")]
 pub trait TokenTrait {
     fn initialize(env: Env, admin: Address, decimal: u32, name: String, symbol: String);
+    fn mint(env: Env, to: Address, amount: i128);
+    fn clawback(env: Env, from: Address, amount: i128);
 }
 
-pub fn create_token(env: &Env, admin: &Address, name: &String, symbol: &String) -> Address {
-    let token_wasm_hash = env.storage().persistent().get(&DataKey::TokenWasmHash).expect("Token Wasm Hash not set");
+#[contractimpl]
+impl RunClubContract {
+    /// Registra o wasm do token KM usado pelos próximos clubes (apenas admin)
+    pub fn set_token_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), RunClubError> {
+        Self::require_admin(&env)?;
+        write_token_wasm_hash(&env, &wasm_hash);
 
-    let token_id = env.deployer().upload_contract_wasm(token_wasm_hash);
+        env.events()
+            .publish((symbol_short!("tok_wasm"),), wasm_hash);
 
-    let token_client = TokenClient::new(env, &token_id);
-    token_client.initialize(admin, 7, name, symbol);
+        Ok(())
+    }
+
+    /// Endereço em que o token KM de um clube é (ou será) implantado
+    pub fn get_club_token_address(env: Env, club_id: u64) -> Result<Address, RunClubError> {
+        Ok(token_address(&env, club_id))
+    }
+}
 
-    token_id
+/// Endereço determinístico do token KM de um clube
+pub fn token_address(env: &Env, club_id: u64) -> Address {
+    env.deployer()
+        .with_current_contract(token_salt(env, club_id))
+        .deployed_address()
 }
 
-pub fn set_token_wasm_hash(env: Env, wasm_hash: soroban_sdk::BytesN<32>) {
-    env.storage().persistent().set(&DataKey::TokenWasmHash, &wasm_hash);
+/// Salt do deploy do token KM: o `club_id` em big-endian nos últimos 8 bytes
+fn token_salt(env: &Env, club_id: u64) -> BytesN<32> {
+    let mut salt = [0u8; 32];
+    salt[24..].copy_from_slice(&club_id.to_be_bytes());
+    BytesN::from_array(env, &salt)
 }
 
+/// Implanta e inicializa o token KM de um clube, com o contrato como administrador
+pub fn create_token(
+    env: &Env,
+    club_id: u64,
+    name: &String,
+    symbol: &String,
+) -> Result<Address, RunClubError> {
+    let token_wasm_hash: BytesN<32> = env
+        .storage()
+        .instance()
+        .get(&DataKey::TokenWasmHash)
+        .ok_or(RunClubError::NotInitialized)?;
 
+    let token_id = env
+        .deployer()
+        .with_current_contract(token_salt(env, club_id))
+        .deploy_v2(token_wasm_hash, ());
+
+    let token_client = TokenClient::new(env, &token_id);
+    token_client.initialize(
+        &env.current_contract_address(),
+        &KM_TOKEN_DECIMALS,
+        name,
+        symbol,
+    );
+
+    Ok(token_id)
+}
+
+pub fn write_token_wasm_hash(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenWasmHash, wasm_hash);
+}
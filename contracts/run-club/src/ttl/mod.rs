@@ -0,0 +1,210 @@
+//! Módulo de gerenciamento do TTL do armazenamento
+//!
+//! Este módulo estende a vida das entradas do contrato sempre que
+//! elas são lidas ou escritas, evitando que clubes, membros e saldos
+//! sejam arquivados no meio de uma temporada.
+
+use soroban_sdk::{contractimpl, Address, Env};
+
+use crate::club_manage::club_queue::ClubQueue;
+use crate::{
+    Club, DataKey, RunClubContract, RunClubContractArgs, RunClubContractClient, RunClubError,
+};
+
+/// Tempo médio de fechamento de um ledger, em segundos
+pub const LEDGER_CLOSE_SECONDS: u64 = 5;
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Janela padrão após o fim do clube em que os corredores ainda podem resgatar
+pub const REDEMPTION_GRACE_PERIOD: u64 = 30 * 24 * 60 * 60;
+/// Menor prazo de resgate aceito por `set_claim_window`
+pub const MIN_CLAIM_WINDOW: u64 = 7 * 24 * 60 * 60;
+/// Maior prazo de resgate aceito por `set_claim_window`
+pub const MAX_CLAIM_WINDOW: u64 = 180 * 24 * 60 * 60;
+/// Maior página de membros e de cada fila estendida por chamada de `bump_club`,
+/// para as entradas tocadas caberem no footprint de uma transação
+pub const MAX_BUMP_PAGE: u32 = 10;
+
+#[contractimpl]
+impl RunClubContract {
+    /// Estende as entradas de um clube até o fim do período mais a janela de resgate
+    /// (qualquer um pode chamar)
+    ///
+    /// Cada chamada estende as entradas do próprio clube e de todas as temporadas, os
+    /// totais de KM da temporada atual e, nas posições `cursor..cursor + limit` (no
+    /// máximo `MAX_BUMP_PAGE`), os membros com os seus KM da temporada atual, os
+    /// pedidos de entrada e a fila de espera.
+    ///
+    /// Os KM e os valores fixados das outras temporadas, o histórico de corridas e os
+    /// saldos de quem já saiu do clube ficam com `bump_member`.
+    pub fn bump_club(env: Env, club_id: u64, cursor: u32, limit: u32) -> Result<u32, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        let ledgers = ledgers_until_expiry(&env, &club);
+
+        bump_until(&env, &DataKey::Club(club_id), ledgers);
+        bump_until(&env, &DataKey::ClubStatusIndex(club_id), ledgers);
+        bump_until(
+            &env,
+            &DataKey::ClubStatusCount(club.status.clone()),
+            ledgers,
+        );
+        if let Some(index) = env
+            .storage()
+            .persistent()
+            .get::<_, u32>(&DataKey::ClubStatusIndex(club_id))
+        {
+            bump_until(
+                &env,
+                &DataKey::ClubsByStatus(club.status.clone(), index),
+                ledgers,
+            );
+        }
+        bump_until(&env, &DataKey::UserClubs(club.organizer.clone()), ledgers);
+        bump_until(&env, &DataKey::ClubOracles(club_id), ledgers);
+        bump_until(&env, &DataKey::ClubRecorders(club_id), ledgers);
+        bump_until(&env, &DataKey::CoOrganizers(club_id), ledgers);
+        for season in 1..=club.season {
+            bump_until(&env, &DataKey::Season(club_id, season), ledgers);
+            bump_until(&env, &DataKey::Settlement(club_id, season), ledgers);
+        }
+        bump_until(
+            &env,
+            &DataKey::SettlementDraft(club_id, club.season),
+            ledgers,
+        );
+        bump_until(&env, &DataKey::KmTotals(club_id, club.season), ledgers);
+
+        let limit = limit.min(MAX_BUMP_PAGE);
+        ClubQueue::JoinRequests.bump_page(&env, club_id, cursor, limit, ledgers);
+        ClubQueue::Waitlist.bump_page(&env, club_id, cursor, limit, ledgers);
+
+        for (offset, member) in Self::member_page(&env, &club, cursor, limit)
+            .iter()
+            .enumerate()
+        {
+            let index = cursor + offset as u32;
+            bump_until(&env, &DataKey::MemberAt(club_id, index), ledgers);
+            bump_until(&env, &DataKey::Member(club_id, member.clone()), ledgers);
+            bump_until(&env, &DataKey::UserClubs(member.clone()), ledgers);
+            bump_until(
+                &env,
+                &DataKey::UserKmTokens(member, club_id, club.season),
+                ledgers,
+            );
+        }
+
+        // A instância guarda a configuração usada no resgate; o token guarda os saldos
+        env.storage().instance().extend_ttl(ledgers, ledgers);
+        env.deployer()
+            .extend_ttl(club.token_address.clone(), ledgers, ledgers);
+
+        Ok(ledgers)
+    }
+
+    /// Estende as entradas de um endereço no clube, mesmo de quem já foi removido ou
+    /// saiu: os KM e os valores fixados de todas as temporadas e as corridas nas
+    /// posições `cursor..cursor + limit` do histórico (qualquer um pode chamar)
+    ///
+    /// Os nonces das atestações valem para o usuário em todos os clubes e não são
+    /// estendidos aqui. Uma entrada persistente expirada é arquivada, não apagada, e
+    /// só volta a ser usada depois de restaurada com o mesmo valor, então um nonce
+    /// expirado continua consumido.
+    pub fn bump_member(
+        env: Env,
+        club_id: u64,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<u32, RunClubError> {
+        let club = Self::load_club(&env, club_id)?;
+        let ledgers = ledgers_until_expiry(&env, &club);
+
+        bump_balances(&env, &club, &user, ledgers);
+        for (offset, run) in Self::run_page(&env, club_id, &user, cursor, limit)
+            .iter()
+            .enumerate()
+        {
+            let index = cursor + offset as u32;
+            bump_until(&env, &DataKey::Run(club_id, user.clone(), index), ledgers);
+            bump_until(&env, &DataKey::RunId(club_id, run.run_id.clone()), ledgers);
+            bump_until(
+                &env,
+                &Self::daily_meters_key(club_id, &user, run.end_timestamp),
+                ledgers,
+            );
+        }
+
+        Ok(ledgers)
+    }
+}
+
+/// Estende o contador de corridas, os KM e os valores fixados de todas as temporadas
+fn bump_balances(env: &Env, club: &Club, user: &Address, ledgers: u32) {
+    bump_until(env, &DataKey::RunCount(club.id, user.clone()), ledgers);
+    for season in 1..=club.season {
+        bump_until(
+            env,
+            &DataKey::UserKmTokens(user.clone(), club.id, season),
+            ledgers,
+        );
+        bump_until(
+            env,
+            &DataKey::Payout(club.id, season, user.clone()),
+            ledgers,
+        );
+    }
+}
+
+/// Estende o TTL da instância (configuração e contador de clubes)
+pub fn bump_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+/// Estende o TTL padrão da instância e do código de outro contrato (o token KM do clube)
+pub fn bump_contract(env: &Env, contract: &Address) {
+    env.deployer().extend_ttl(
+        contract.clone(),
+        INSTANCE_LIFETIME_THRESHOLD,
+        INSTANCE_BUMP_AMOUNT,
+    );
+}
+
+/// Estende o TTL padrão de uma entrada persistente, se ela existir
+pub fn bump(env: &Env, key: &DataKey) {
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(
+            key,
+            PERSISTENT_LIFETIME_THRESHOLD,
+            PERSISTENT_BUMP_AMOUNT,
+        );
+    }
+}
+
+/// Garante que uma entrada persistente viva pelo menos `ledgers`, se ela existir
+pub fn bump_until(env: &Env, key: &DataKey, ledgers: u32) {
+    if env.storage().persistent().has(key) {
+        env.storage().persistent().extend_ttl(key, ledgers, ledgers);
+    }
+}
+
+/// Ledgers até `month_end_timestamp` mais a janela de resgate do clube, nunca menos
+/// que o TTL padrão nem mais que o máximo da rede
+pub(crate) fn ledgers_until_expiry(env: &Env, club: &Club) -> u32 {
+    let expiry = club
+        .month_end_timestamp
+        .saturating_add(club.claim_window_secs);
+    let seconds = expiry.saturating_sub(env.ledger().timestamp());
+    let ledgers = seconds.div_ceil(LEDGER_CLOSE_SECONDS);
+
+    ledgers
+        .max(PERSISTENT_BUMP_AMOUNT as u64)
+        .min(env.storage().max_ttl() as u64) as u32
+}
@@ -0,0 +1,37 @@
+//! Layouts congelados da versão 1 do schema, a única implantada antes da atual
+//!
+//! Cada struct guarda os campos exatamente como a versão os salvava. Um
+//! `contracttype` struct é salvo como um mapa pelos nomes dos campos, então só
+//! o layout de mesmos campos lê as entradas daquela versão. Os layouts ficam
+//! fora da especificação do contrato.
+
+use soroban_sdk::{contracttype, Address, String, Vec};
+
+use crate::WithdrawalRule;
+
+/// Layout do `Club` na versão 1 do schema, com os membros dentro do próprio clube
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) struct ClubV1 {
+    pub id: u64,
+    pub name: String,
+    pub organizer: Address,
+    pub members: Vec<Address>,
+    pub usdc_deposited: i128,
+    pub usdc_per_km: i128,
+    pub withdrawal_rule: WithdrawalRule,
+    pub month_end_timestamp: u64,
+    pub is_active: bool,
+    pub token_address: Address,
+}
+
+/// Chaves da versão 1 que mudaram de formato
+///
+/// Um `contracttype` enum é salvo como `[variante, campos...]`, então a mesma
+/// variante com os campos antigos lê as entradas gravadas pela versão 1.
+#[contracttype(export = false)]
+#[derive(Clone)]
+pub(super) enum LegacyDataKey {
+    /// KM inteiros de um membro, antes das temporadas
+    UserKmTokens(Address, u64),
+}
@@ -0,0 +1,175 @@
+//! Migração da versão 1 do schema para a atual
+//!
+//! Cada chamada migra uma página de clubes e membros e guarda onde parou em
+//! `DataKey::MigrationCursor`. Um clube só é regravado no layout atual depois
+//! que todos os seus membros foram migrados; os clubes removidos na versão 1
+//! não existem mais e ficam de fora.
+
+use soroban_sdk::{token, Address, Env, String};
+
+use super::layouts::{ClubV1, LegacyDataKey};
+use crate::redemption::reward_rules::{empty_totals, tally};
+use crate::token_factory::{self, TokenClient};
+use crate::{
+    Club, ClubStatus, DataKey, Member, MemberRole, MembershipPolicy, RemovedKmPolicy,
+    RunClubContract, RunClubError, RunLimits, Season, SurplusRule, KM_UNIT,
+    REDEMPTION_GRACE_PERIOD,
+};
+
+/// Migra até `limit` clubes e membros da versão 1 e devolve se a migração terminou
+///
+/// Os membros saem do vetor para `Member` e `MemberAt`, na mesma ordem, e os KM
+/// inteiros passam para a temporada 1 em unidades do token, com os tokens KM que
+/// faltarem emitidos. Clubes criados antes do token factory guardam um endereço de
+/// token que nunca foi implantado; eles ganham o token KM no endereço determinístico
+/// do clube. Os campos novos do `Club` recebem os valores padrão de um clube novo,
+/// com `created_at` 0 e a temporada 1 começando em 0.
+pub(super) fn migrate_v1(env: &Env, limit: u32) -> Result<bool, RunClubError> {
+    let club_counter: u64 = env
+        .storage()
+        .persistent()
+        .get(&DataKey::ClubCounter)
+        .unwrap_or(0);
+    let (mut club_id, mut index): (u64, u32) = env
+        .storage()
+        .instance()
+        .get(&DataKey::MigrationCursor)
+        .unwrap_or((1, 0));
+
+    let mut budget = limit;
+    while club_id <= club_counter && budget > 0 {
+        let Some(old) = env
+            .storage()
+            .persistent()
+            .get::<_, ClubV1>(&DataKey::Club(club_id))
+        else {
+            club_id += 1;
+            budget -= 1;
+            continue;
+        };
+
+        // Cada página com o clube migra pelo menos um membro, então o token só é
+        // criado uma vez
+        let token_address = token_factory::token_address(env, club_id);
+        if index == 0 && old.token_address != token_address {
+            token_factory::create_token(env, club_id, &old.name, &String::from_str(env, "KM"))?;
+        }
+
+        while index < old.members.len() && budget > 0 {
+            let member = old.members.get(index).unwrap();
+            migrate_member(env, &old, &member, index, &token_address);
+            index += 1;
+            budget -= 1;
+        }
+
+        if index == old.members.len() {
+            finish_club(env, old, token_address);
+            club_id += 1;
+            index = 0;
+            budget = budget.saturating_sub(1);
+        }
+    }
+
+    if club_id > club_counter {
+        env.storage().instance().remove(&DataKey::MigrationCursor);
+        return Ok(true);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationCursor, &(club_id, index));
+    Ok(false)
+}
+
+/// Dá ao membro os registros próprios e move os seus KM para a temporada 1, somando-os
+/// aos totais da temporada
+fn migrate_member(env: &Env, club: &ClubV1, user: &Address, index: u32, token_address: &Address) {
+    let storage = env.storage().persistent();
+    let role = if *user == club.organizer {
+        MemberRole::Organizer
+    } else {
+        MemberRole::Member
+    };
+    storage.set(
+        &DataKey::Member(club.id, user.clone()),
+        &Member {
+            joined_at: 0,
+            role,
+            index,
+        },
+    );
+    storage.set(&DataKey::MemberAt(club.id, index), user);
+    RunClubContract::link_user_club(env, user, club.id, MemberRole::Member);
+
+    let key = LegacyDataKey::UserKmTokens(user.clone(), club.id);
+    if let Some(km) = storage.get::<_, i128>(&key) {
+        let km = km * KM_UNIT;
+        storage.remove(&key);
+        storage.set(&DataKey::UserKmTokens(user.clone(), club.id, 1), &km);
+        mint_up_to(env, token_address, user, km);
+
+        let totals_key = DataKey::KmTotals(club.id, 1);
+        let mut totals = storage
+            .get(&totals_key)
+            .unwrap_or_else(|| empty_totals(env));
+        tally(&club.withdrawal_rule, &mut totals, km);
+        storage.set(&totals_key, &totals);
+    }
+}
+
+/// Regrava o clube no layout atual, com a temporada 1 e a entrada no índice de estados
+fn finish_club(env: &Env, old: ClubV1, token_address: Address) {
+    let status = if old.is_active {
+        ClubStatus::Active
+    } else {
+        ClubStatus::Draft
+    };
+    RunClubContract::push_status_entry(env, old.id, status.clone());
+    RunClubContract::write_season(
+        env,
+        old.id,
+        &Season {
+            season: 1,
+            start_timestamp: 0,
+            end_timestamp: old.month_end_timestamp,
+            rolled_over: 0,
+        },
+    );
+    RunClubContract::link_user_club(env, &old.organizer, old.id, MemberRole::Organizer);
+
+    env.storage().persistent().set(
+        &DataKey::Club(old.id),
+        &Club {
+            id: old.id,
+            name: old.name,
+            organizer: old.organizer,
+            pending_organizer: None,
+            member_count: old.members.len(),
+            max_members: None,
+            usdc_deposited: old.usdc_deposited,
+            usdc_per_km: old.usdc_per_km,
+            withdrawal_rule: old.withdrawal_rule,
+            surplus_rule: SurplusRule::ProRata,
+            removed_km_policy: RemovedKmPolicy::Forfeit,
+            membership_policy: MembershipPolicy::Open,
+            start_timestamp: 0,
+            registration_close_timestamp: old.month_end_timestamp,
+            month_end_timestamp: old.month_end_timestamp,
+            season: 1,
+            claim_window_secs: REDEMPTION_GRACE_PERIOD,
+            is_active: old.is_active,
+            token_address,
+            created_at: 0,
+            status,
+            limits: RunLimits::default(),
+        },
+    );
+}
+
+/// Emite tokens KM até o saldo do membro chegar a `km`, para o token acompanhar os
+/// KM registrados no clube
+fn mint_up_to(env: &Env, token_address: &Address, member: &Address, km: i128) {
+    let balance = token::Client::new(env, token_address).balance(member);
+    if km > balance {
+        TokenClient::new(env, token_address).mint(member, &(km - balance));
+    }
+}
@@ -0,0 +1,85 @@
+//! Módulo de atualização do contrato
+//!
+//! Este módulo troca o wasm do contrato sem perder o estado e migra
+//! os registros salvos em layouts antigos para a versão atual do
+//! schema de armazenamento.
+//!
+//! Só a versão 1 do schema chegou a ser implantada antes da atual, então a
+//! migração é um único passo paginado, com o layout da versão 1 congelado em
+//! `layouts`.
+
+use soroban_sdk::{contractimpl, symbol_short, BytesN, Env};
+
+use crate::{
+    DataKey, RunClubContract, RunClubContractArgs, RunClubContractClient, RunClubError,
+    MAX_PAGE_LIMIT,
+};
+
+mod layouts;
+mod migrations;
+
+/// Versão atual do schema de armazenamento
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[contractimpl]
+impl RunClubContract {
+    /// Troca o wasm do contrato mantendo o estado (apenas admin)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), RunClubError> {
+        Self::require_admin(&env)?;
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        env.events()
+            .publish((symbol_short!("upgrade"),), new_wasm_hash);
+
+        Ok(())
+    }
+
+    /// Migra o armazenamento até a versão atual do schema (apenas admin)
+    ///
+    /// Deve ser chamada logo após o `upgrade`, de novo a cada página, até devolver
+    /// `SCHEMA_VERSION`. Cada chamada migra no máximo `limit` clubes e membros (até
+    /// `MAX_PAGE_LIMIT`) e não faz nada se o schema já estiver atualizado.
+    pub fn migrate(env: Env, limit: u32) -> Result<u32, RunClubError> {
+        Self::require_admin(&env)?;
+
+        match Self::schema_version(&env) {
+            SCHEMA_VERSION => return Ok(SCHEMA_VERSION),
+            1 => {}
+            _ => return Err(RunClubError::UnsupportedSchemaVersion),
+        }
+
+        if !migrations::migrate_v1(&env, limit.min(MAX_PAGE_LIMIT))? {
+            return Ok(1);
+        }
+
+        write_schema_version(&env, SCHEMA_VERSION);
+        env.events()
+            .publish((symbol_short!("migrate"),), (1u32, SCHEMA_VERSION));
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    /// Versão do schema de armazenamento em uso
+    pub fn get_schema_version(env: Env) -> Result<u32, RunClubError> {
+        Self::admin(&env)?;
+        Ok(Self::schema_version(&env))
+    }
+}
+
+impl RunClubContract {
+    /// Contratos implantados antes do versionamento estão na versão 1
+    pub(crate) fn schema_version(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+}
+
+pub fn write_schema_version(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SchemaVersion, &version);
+}
@@ -0,0 +1,182 @@
+//! Teste TC-018: Poeira do Arredondamento, Prazo de Resgate e Recolhimento
+//!
+//! Objetivo: Verificar que o fechamento distribui a poeira de forma determinística e
+//! que, depois do prazo de resgate, o organizador recolhe ou reaproveita o USDC
+//! não resgatado.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • "Clube Alpha" tem 100 USDC, regra de "Distribuição Igual" e prazo de resgate de 7 dias
+//! • Usuário B correu 5 km, Usuário C correu 3 km e Usuário D correu 1 km
+//!
+//! Cenário:
+//! 1. O período termina e o clube é fechado
+//! 2. Usuário B resgata dentro do prazo; Usuário C e Usuário D não resgatam
+//! 3. Depois do prazo, Usuário A recolhe o USDC ou o passa para outro clube
+//!
+//! Resultados Esperados:
+//! • 100 / 3 deixa 1 USDC de poeira, que vai para Usuário B (primeiro da lista)
+//! • O prazo de resgate fica entre 7 e 180 dias
+//! • Ninguém resgata depois do prazo e o organizador só recolhe depois dele
+//! • O USDC recolhido vai para o organizador ou para o pool de outro clube ainda aberto
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env, String};
+
+mod common;
+
+use run_club::{
+    ClubConfig, ClubStatus, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule,
+};
+
+use common::DAY;
+
+fn create_club(client: &RunClubContractClient, env: &Env, organizer: &Address) -> u64 {
+    client.create_club(
+        organizer,
+        &String::from_str(env, "Clube Alpha"),
+        &common::club_config(WithdrawalRule::Equal),
+    )
+}
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    RunClubContractClient<'a>,
+    TokenClient<'a>,
+    u64,
+    Address,
+    [Address; 3],
+) {
+    let (client, usdc, club_id, organizer) =
+        common::setup_club(env, &common::club_config(WithdrawalRule::Equal), 100i128);
+    client.set_claim_window(&club_id, &organizer, &(7 * DAY));
+
+    let members = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for (member, km) in members.iter().zip([5i128, 3, 1]) {
+        client.add_member(&club_id, member);
+        common::add_km(env, &client, club_id, member, km);
+    }
+
+    (client, usdc, club_id, organizer, members)
+}
+
+#[test]
+fn test_tc018_dust_and_claim_window() {
+    let env = Env::default();
+    let (client, usdc, club_id, organizer, [member_b, member_c, _member_d]) = setup(&env);
+    let month_end = client.get_club(&club_id).month_end_timestamp;
+
+    // O prazo só pode ser definido pelo organizador, antes do fim do período
+    assert_eq!(
+        client.try_set_claim_window(&club_id, &member_b, &DAY),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    // O prazo fica entre 7 e 180 dias
+    for window in [0u64, 1, 7 * DAY - 1, 180 * DAY + 1, u64::MAX] {
+        assert_eq!(
+            client.try_set_claim_window(&club_id, &organizer, &window),
+            Err(Ok(RunClubError::InvalidDuration))
+        );
+    }
+    client.set_claim_window(&club_id, &organizer, &(180 * DAY));
+    client.set_claim_window(&club_id, &organizer, &(7 * DAY));
+
+    common::set_time(&env, month_end + 1);
+    assert_eq!(
+        client.try_set_claim_window(&club_id, &organizer, &(30 * DAY)),
+        Err(Ok(RunClubError::ClubPeriodEnded))
+    );
+
+    // 100 / 3 = 33 para cada um e 1 de poeira para o primeiro da lista
+    let settlement = client.settle_club(&club_id, &10u32).unwrap();
+    assert_eq!(settlement.dust, 1i128);
+    assert_eq!(settlement.total_payout, 100i128);
+    assert_eq!(settlement.claim_deadline, month_end + 7 * DAY);
+    assert_eq!(client.get_payout(&club_id, &member_b).amount, 34i128);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 33i128);
+
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 34i128);
+
+    // O organizador espera o prazo acabar
+    assert_eq!(
+        client.try_sweep_unclaimed(&club_id, &organizer),
+        Err(Ok(RunClubError::ClaimWindowOpen))
+    );
+
+    common::set_time(&env, month_end + 7 * DAY + 1);
+    assert_eq!(
+        client.try_redeem_usdc(&club_id, &member_c, &member_c),
+        Err(Ok(RunClubError::ClaimWindowClosed))
+    );
+    assert_eq!(
+        client.try_sweep_unclaimed(&club_id, &member_b),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    assert_eq!(client.sweep_unclaimed(&club_id, &organizer), 66i128);
+    assert_eq!(usdc.balance(&organizer), 66i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+    assert_eq!(client.get_settlement(&club_id).swept, 66i128);
+    assert_eq!(
+        client.try_sweep_unclaimed(&club_id, &organizer),
+        Err(Ok(RunClubError::NothingToSweep))
+    );
+}
+
+#[test]
+fn test_tc018_unclaimed_usdc_rolls_into_another_club() {
+    let env = Env::default();
+    let (client, usdc, club_id, organizer, [member_b, _member_c, _member_d]) = setup(&env);
+
+    // Um clube do mesmo período já venceu e não recebe o saldo
+    let expired_club_id = create_club(&client, &env, &organizer);
+    let other_club_id = create_club(&client, &env, &member_b);
+
+    let month_end = client.get_club(&club_id).month_end_timestamp;
+    let now = month_end + 7 * DAY + 1;
+    common::set_time(&env, now);
+
+    // Próximo clube do organizador, ainda em rascunho
+    let next_club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Beta"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: now,
+            registration_close_timestamp: now + common::days(30),
+            end_timestamp: now + common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    assert_eq!(
+        client.try_rollover_unclaimed(&club_id, &organizer, &expired_club_id),
+        Err(Ok(RunClubError::ClubNotActive))
+    );
+
+    // O clube é fechado na hora, se ninguém o fechou antes
+    assert_eq!(
+        client.try_rollover_unclaimed(&club_id, &organizer, &other_club_id),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    assert_eq!(
+        client.rollover_unclaimed(&club_id, &organizer, &next_club_id),
+        100i128
+    );
+    assert_eq!(client.get_club(&club_id).status, ClubStatus::Settled);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+
+    let next_club = client.get_club(&next_club_id);
+    assert_eq!(next_club.usdc_deposited, 100i128);
+    assert_eq!(next_club.status, ClubStatus::Active);
+    assert_eq!(usdc.balance(&client.address), 100i128);
+}
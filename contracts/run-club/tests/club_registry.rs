@@ -0,0 +1,133 @@
+//! Teste TC-010: Registro de Clubes por Estado
+//!
+//! Objetivo: Verificar que o registro acompanha o ciclo de vida dos clubes e
+//! permite listar clubes por estado de forma paginada.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//!
+//! Cenário:
+//! 1. Usuário A cria três clubes
+//! 2. Dois clubes são ativados (um por `activate`, outro pelo depósito)
+//! 3. O período termina, o clube sem depósito é encerrado e o outro ativo é alterado
+//! 4. O clube em rascunho é removido
+//!
+//! Resultados Esperados:
+//! • Cada clube aparece apenas na lista do seu estado atual
+//! • Clubes com o período vencido saem da listagem e passam a `Ended` na próxima escrita
+//! • As listas são paginadas e retornam resumos dos clubes
+//! • Clubes removidos continuam listados, mas não podem mais ser usados
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+
+mod common;
+
+use run_club::{
+    ClubConfig, ClubStatus, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule,
+};
+
+#[test]
+fn test_tc010_club_registry_follows_lifecycle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+
+    let mut clubs = [0u64; 3];
+    for (i, name) in ["Clube Alpha", "Clube Beta", "Clube Gama"]
+        .iter()
+        .enumerate()
+    {
+        clubs[i] = client.create_club(
+            &organizer,
+            &String::from_str(&env, name),
+            &ClubConfig {
+                usdc_per_km: 1i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(30),
+                end_timestamp: common::days(30),
+                limits: RunLimits::default(),
+                max_members: None,
+            },
+        );
+    }
+    let [alpha, beta, gama] = clubs;
+
+    // Todos começam em rascunho
+    assert_eq!(client.get_club_count_by_status(&ClubStatus::Draft), 3);
+    assert_eq!(client.get_club(&alpha).status, ClubStatus::Draft);
+    let page = client.get_clubs_by_status(&ClubStatus::Draft, &0u32, &2u32);
+    assert_eq!(page.len(), 2);
+    let page = client.get_clubs_by_status(&ClubStatus::Draft, &2u32, &2u32);
+    assert_eq!(page.len(), 1);
+
+    // Ativação manual e pelo depósito
+    client.activate(&alpha, &organizer);
+    client.deposit_usdc(&beta, &organizer, &100i128);
+
+    let active = client.get_active_clubs(&0u32, &10u32);
+    assert_eq!(active.len(), 2);
+    assert!(active.iter().all(|club| club.status == ClubStatus::Active));
+    let beta_summary = active.iter().find(|club| club.id == beta).unwrap();
+    assert_eq!(beta_summary.name, String::from_str(&env, "Clube Beta"));
+    assert_eq!(beta_summary.usdc_deposited, 100i128);
+    let draft = client.get_clubs_by_status(&ClubStatus::Draft, &0u32, &10u32);
+    assert_eq!(draft.len(), 1);
+    assert_eq!(draft.get(0).unwrap().id, gama);
+
+    // Ninguém encerra o clube antes do fim do período
+    assert_eq!(
+        client.try_end_club(&alpha),
+        Err(Ok(RunClubError::ClubPeriodNotEnded))
+    );
+
+    // Fim do período: qualquer um pode encerrar o clube
+    env.ledger().with_mut(|li| {
+        li.timestamp += 31 * 24 * 60 * 60;
+    });
+    client.end_club(&alpha);
+    client.end_club(&alpha); // encerrar de novo não tem efeito
+    assert_eq!(client.get_club(&alpha).status, ClubStatus::Ended);
+    assert!(!client.get_club(&alpha).is_active);
+
+    let ended = client.get_clubs_by_status(&ClubStatus::Ended, &0u32, &10u32);
+    assert_eq!(ended.len(), 1);
+    assert_eq!(ended.get(0).unwrap().id, alpha);
+
+    // Beta também venceu: some da listagem mesmo antes de ser encerrado
+    assert_eq!(client.get_club_count_by_status(&ClubStatus::Active), 1);
+    assert_eq!(client.get_active_clubs(&0u32, &10u32).len(), 0);
+
+    // A próxima escrita no clube vencido o encerra
+    client.propose_organizer(&beta, &organizer, &Address::generate(&env));
+    assert_eq!(client.get_club(&beta).status, ClubStatus::Ended);
+    assert_eq!(client.get_club_count_by_status(&ClubStatus::Active), 0);
+    assert_eq!(client.get_club_count_by_status(&ClubStatus::Ended), 2);
+
+    // Clube removido sai do rascunho e vai para a lista de removidos
+    client.remove_club(&gama, &organizer, &10u32);
+    assert_eq!(client.get_club_count_by_status(&ClubStatus::Draft), 0);
+    let removed = client.get_clubs_by_status(&ClubStatus::Removed, &0u32, &10u32);
+    assert_eq!(removed.len(), 1);
+    assert_eq!(removed.get(0).unwrap().id, gama);
+    assert_eq!(
+        client.try_get_club(&gama),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+    assert_eq!(
+        client.try_activate(&gama, &organizer),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+}
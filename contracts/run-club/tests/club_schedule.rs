@@ -0,0 +1,139 @@
+//! Teste TC-020: Início Agendado e Período de Inscrições
+//!
+//! Objetivo: Verificar que o clube só aceita membros durante as inscrições e só
+//! credita corridas feitas dentro do período ativo.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A cria o "Clube Alpha" começando em 1 dia, com inscrições até o 2º dia
+//!   e fim no 10º dia
+//!
+//! Cenário:
+//! 1. Usuário B entra no clube antes do início e corre antes e depois dele
+//! 2. Usuário C tenta entrar depois do fim das inscrições
+//! 3. Usuário A tenta criar clubes com calendários incoerentes
+//!
+//! Resultados Esperados:
+//! • Corridas que começam antes do início não são creditadas
+//! • Corridas feitas dentro do período são creditadas
+//! • Ninguém entra depois do fim das inscrições
+//! • Calendários incoerentes são rejeitados na criação do clube
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RunClubContractClient, RunClubError, RunLimits, SurplusRule, WithdrawalRule,
+    KM_UNIT,
+};
+
+use common::DAY;
+
+#[test]
+fn test_tc020_registration_window_and_active_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: DAY,
+            registration_close_timestamp: 2 * DAY,
+            end_timestamp: 10 * DAY,
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+
+    let club = client.get_club(&club_id);
+    assert_eq!(club.start_timestamp, DAY);
+    assert_eq!(club.registration_close_timestamp, 2 * DAY);
+    assert_eq!(club.month_end_timestamp, 10 * DAY);
+    assert_eq!(client.get_season(&club_id, &1u32).start_timestamp, DAY);
+
+    // Inscrição antes do início
+    let member_b = Address::generate(&env);
+    client.add_member(&club_id, &member_b);
+
+    let (attestation, oracle_key, signature) =
+        common::attest_km(&env, &client, club_id, &member_b, 1);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::RunOutsidePeriod))
+    );
+
+    // Corrida que começou antes do início, mas terminou depois
+    common::set_time(&env, DAY + 60);
+    let (attestation, oracle_key, signature) =
+        common::attest_km(&env, &client, club_id, &member_b, 1);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::RunOutsidePeriod))
+    );
+
+    common::set_time(&env, DAY + 3_600);
+    common::add_km(&env, &client, club_id, &member_b, 5);
+    assert_eq!(client.get_user_km_tokens(&member_b, &club_id), 5 * KM_UNIT);
+
+    // Inscrições encerradas
+    common::set_time(&env, 2 * DAY + 1);
+    let member_c = Address::generate(&env);
+    assert_eq!(
+        client.try_add_member(&club_id, &member_c),
+        Err(Ok(RunClubError::RegistrationClosed))
+    );
+    assert_eq!(client.get_club(&club_id).member_count, 1);
+}
+
+#[test]
+fn test_tc020_invalid_schedules_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+    common::set_time(&env, 5 * DAY);
+
+    let organizer = Address::generate(&env);
+    let schedules = [
+        // Fim antes do início
+        (6 * DAY, 6 * DAY, 6 * DAY, RunClubError::InvalidDuration),
+        (8 * DAY, 7 * DAY, 7 * DAY, RunClubError::InvalidDuration),
+        // Inscrições depois do fim
+        (6 * DAY, 9 * DAY, 8 * DAY, RunClubError::InvalidSchedule),
+        // Período já encerrado
+        (DAY, DAY, 2 * DAY, RunClubError::InvalidSchedule),
+    ];
+    for (start, registration_close, end, error) in schedules {
+        assert_eq!(
+            client.try_create_club(
+                &organizer,
+                &String::from_str(&env, "Clube Alpha"),
+                &ClubConfig {
+                    usdc_per_km: 1i128,
+                    withdrawal_rule: WithdrawalRule::Equal,
+                    surplus_rule: SurplusRule::ProRata,
+                    start_timestamp: start,
+                    registration_close_timestamp: registration_close,
+                    end_timestamp: end,
+                    limits: RunLimits::default(),
+                    max_members: None,
+                },
+            ),
+            Err(Ok(error))
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Utilitários compartilhados pelos testes de integração
+
+#![allow(dead_code)]
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    contractclient,
+    testutils::{Address as _, Ledger},
+    token,
+    xdr::ToXdr,
+    Address, BytesN, Env, String,
+};
+
+use run_club::{
+    ClubConfig, RunAttestation, RunClubContract, RunClubContractClient, RunLimits, SurplusRule,
+    WithdrawalRule,
+};
+
+/// Registra um Stellar Asset Contract que faz o papel do USDC
+pub fn create_usdc<'a>(env: &Env) -> token::Client<'a> {
+    let issuer = Address::generate(env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    token::Client::new(env, &sac.address())
+}
+
+/// Emite USDC para um endereço (o emissor é mockado pelo `mock_all_auths`)
+pub fn mint_usdc(env: &Env, usdc: &token::Client, to: &Address, amount: i128) {
+    token::StellarAssetClient::new(env, &usdc.address).mint(to, &amount);
+}
+
+/// Wasm do token KM implantado pelo contrato para cada clube
+pub const KM_TOKEN_WASM: &[u8] = include_bytes!("../fixtures/km_token.wasm");
+
+/// Funções administrativas do token KM que não fazem parte do SEP-41
+#[contractclient(name = "KmTokenClient")]
+pub trait KmTokenAdmin {
+    fn initialize(env: Env, admin: Address, decimal: u32, name: String, symbol: String);
+    fn mint(env: Env, to: Address, amount: i128);
+    fn admin(env: Env) -> Address;
+}
+
+/// Publica o wasm do token KM no ledger de testes e retorna seu hash
+pub fn upload_km_token(env: &Env) -> BytesN<32> {
+    env.deployer().upload_contract_wasm(KM_TOKEN_WASM)
+}
+
+/// Implanta um token KM avulso administrado por `admin`, como o de um clube antigo
+pub fn register_km_token(env: &Env, admin: &Address) -> Address {
+    let address = env.register(KM_TOKEN_WASM, ());
+    KmTokenClient::new(env, &address).initialize(
+        admin,
+        &7u32,
+        &String::from_str(env, "Clube Legado"),
+        &String::from_str(env, "KM"),
+    );
+    address
+}
+
+/// Segundos em um dia
+pub const DAY: u64 = 24 * 60 * 60;
+
+/// Timestamp `days` dias depois do início do ledger de teste
+pub fn days(days: u64) -> u64 {
+    days * DAY
+}
+
+/// Move o relógio do ledger de teste para `timestamp`
+pub fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+/// Move o relógio para logo depois do fim do período do clube
+pub fn end_period(env: &Env, client: &RunClubContractClient, club_id: u64) {
+    set_time(env, client.get_club(&club_id).month_end_timestamp + 1);
+}
+
+/// Taxa da plataforma usada nos testes (2,5%)
+pub const PLATFORM_FEE_BPS: u32 = 250;
+
+/// Implanta o contrato com um admin, o USDC, o wasm do token KM e o oráculo padrão
+///
+/// O `add_oracle` exige a assinatura do admin, então o `mock_all_auths` já deve estar
+/// ativo.
+pub fn register<'a>(env: &Env) -> (Address, token::Client<'a>) {
+    let admin = Address::generate(env);
+    let usdc = create_usdc(env);
+    let contract_id = env.register(
+        RunClubContract,
+        (
+            admin,
+            usdc.address.clone(),
+            upload_km_token(env),
+            PLATFORM_FEE_BPS,
+        ),
+    );
+    RunClubContractClient::new(env, &contract_id).add_oracle(&Oracle::default().public_key(env));
+    (contract_id, usdc)
+}
+
+/// Clube de 30 dias com a regra `withdrawal_rule`, 1 USDC por km e a sobra dividida
+/// na proporção dos KM
+pub fn club_config(withdrawal_rule: WithdrawalRule) -> ClubConfig {
+    ClubConfig {
+        usdc_per_km: 1i128,
+        withdrawal_rule,
+        surplus_rule: SurplusRule::ProRata,
+        start_timestamp: 0u64,
+        registration_close_timestamp: days(30),
+        end_timestamp: days(30),
+        limits: RunLimits::default(),
+        max_members: None,
+    }
+}
+
+/// Implanta o contrato e cria o "Clube Alpha" de um organizador novo com `config` e
+/// `pool` USDC depositados
+///
+/// Liga o `mock_all_auths` antes de implantar o contrato.
+pub fn setup_club<'a>(
+    env: &Env,
+    config: &ClubConfig,
+    pool: i128,
+) -> (RunClubContractClient<'a>, token::Client<'a>, u64, Address) {
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = register(env);
+    let client = RunClubContractClient::new(env, &contract_id);
+
+    let organizer = Address::generate(env);
+    let club_id = client.create_club(&organizer, &String::from_str(env, "Clube Alpha"), config);
+    if pool > 0 {
+        mint_usdc(env, &usdc, &organizer, pool);
+        client.deposit_usdc(&club_id, &organizer, &pool);
+    }
+
+    (client, usdc, club_id, organizer)
+}
+
+/// Nonces únicos entre todos os testes
+static NEXT_NONCE: AtomicU64 = AtomicU64::new(1);
+
+/// Oráculo de atividade com uma chave ed25519 determinística
+pub struct Oracle {
+    key: SigningKey,
+}
+
+impl Default for Oracle {
+    /// Oráculo global registrado pelo `register`
+    fn default() -> Self {
+        Self::new([7; 32])
+    }
+}
+
+impl Oracle {
+    pub fn new(seed: [u8; 32]) -> Self {
+        Self {
+            key: SigningKey::from_bytes(&seed),
+        }
+    }
+
+    pub fn public_key(&self, env: &Env) -> BytesN<32> {
+        BytesN::from_array(env, &self.key.verifying_key().to_bytes())
+    }
+
+    /// Atestação de uma corrida que termina agora, com run id e nonce novos
+    pub fn attest(&self, env: &Env, user: &Address, club_id: u64, meters: u64) -> RunAttestation {
+        let nonce = NEXT_NONCE.fetch_add(1, Ordering::Relaxed);
+        let mut run_id = [0u8; 32];
+        run_id[24..].copy_from_slice(&nonce.to_be_bytes());
+
+        let end_timestamp = env.ledger().timestamp();
+        RunAttestation {
+            user: user.clone(),
+            club_id,
+            run_id: BytesN::from_array(env, &run_id),
+            distance_meters: meters,
+            start_timestamp: end_timestamp.saturating_sub(meters * 36 / 100), // 6 min/km
+            end_timestamp,
+            nonce,
+        }
+    }
+
+    /// Assina a atestação para o contrato `contract_id`
+    pub fn sign(
+        &self,
+        env: &Env,
+        contract_id: &Address,
+        attestation: &RunAttestation,
+    ) -> BytesN<64> {
+        let message = (contract_id.clone(), attestation.clone()).to_xdr(env);
+        let mut bytes = vec![0u8; message.len() as usize];
+        message.copy_into_slice(&mut bytes);
+        BytesN::from_array(env, &self.key.sign(&bytes).to_bytes())
+    }
+}
+
+/// Atestação de `km` quilômetros assinada pelo oráculo padrão
+pub fn attest_km(
+    env: &Env,
+    client: &RunClubContractClient,
+    club_id: u64,
+    user: &Address,
+    km: i128,
+) -> (RunAttestation, BytesN<32>, BytesN<64>) {
+    let oracle = Oracle::default();
+    let attestation = oracle.attest(env, user, club_id, km as u64 * 1000);
+    let signature = oracle.sign(env, &client.address, &attestation);
+    (attestation, oracle.public_key(env), signature)
+}
+
+/// Credita `km` quilômetros ao corredor com uma atestação do oráculo padrão
+pub fn add_km(env: &Env, client: &RunClubContractClient, club_id: u64, user: &Address, km: i128) {
+    add_meters(env, client, club_id, user, km as u64 * 1000);
+}
+
+/// Credita uma corrida de `meters` metros com uma atestação do oráculo padrão
+pub fn add_meters(
+    env: &Env,
+    client: &RunClubContractClient,
+    club_id: u64,
+    user: &Address,
+    meters: u64,
+) {
+    let oracle = Oracle::default();
+    let attestation = oracle.attest(env, user, club_id, meters);
+    let signature = oracle.sign(env, &client.address, &attestation);
+    client.add_km_tokens(&attestation, &oracle.public_key(env), &signature);
+}
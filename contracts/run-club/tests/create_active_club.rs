@@ -10,22 +10,27 @@
 
 #![cfg(test)]
 
-use soroban_sdk::{testutils::Address as _, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String, Vec,
+};
 
-use run_club::{RunClubContract, RunClubContractClient, WithdrawalRule};
+mod common;
+
+use run_club::{
+    ClubConfig, MemberRole, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule,
+};
 
 #[test]
 fn test_tc001_create_activate_and_add_members_to_club() {
     let env = Env::default();
     env.mock_all_auths();
 
-    // Registrar o contrato
-    let contract_id = env.register(RunClubContract, ());
+    // Implantar o contrato já configurado
+    let (contract_id, _) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
 
-    // Inicializar o contrato
-    client.initialize();
-
     // === CONFIGURAÇÃO DOS USUÁRIOS ===
     // Usuário A (Organizador)
     let organizer = Address::generate(&env);
@@ -42,15 +47,22 @@ fn test_tc001_create_activate_and_add_members_to_club() {
     let club_name = String::from_str(&env, "Clube Alpha");
     let usdc_per_km = 1i128; // 1 USDC por KM
     let withdrawal_rule = WithdrawalRule::Equal;
-    let duration_days = 30u32; // 30 dias de duração
+    let duration_days = 30u64; // 30 dias de duração
 
     // Criar o clube
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &usdc_per_km,
-        &withdrawal_rule,
-        &duration_days,
+        &ClubConfig {
+            usdc_per_km,
+            withdrawal_rule: withdrawal_rule.clone(),
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(duration_days),
+            end_timestamp: common::days(duration_days),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     // Verificar que o clube foi criado com sucesso
@@ -64,8 +76,8 @@ fn test_tc001_create_activate_and_add_members_to_club() {
     assert_eq!(club.usdc_deposited, 0i128); // Ainda não foi depositado
     assert_eq!(club.usdc_per_km, usdc_per_km);
     assert_eq!(club.withdrawal_rule, withdrawal_rule);
-    assert_eq!(club.is_active, false); // Ainda não está ativo
-    assert_eq!(club.members.len(), 0); // Ainda não tem membros
+    assert!(!club.is_active); // Ainda não está ativo
+    assert_eq!(club.member_count, 0); // Ainda não tem membros
 
     // === PASSO 4: ATIVAÇÃO DO CLUBE ===
     // Usuário A ativa o clube
@@ -73,12 +85,12 @@ fn test_tc001_create_activate_and_add_members_to_club() {
 
     // Verificar que o clube foi ativado
     let club_after_activation = client.get_club(&club_id);
-    assert_eq!(club_after_activation.is_active, true); // Clube agora está ativo
+    assert!(club_after_activation.is_active); // Clube agora está ativo
 
     // Verificar que o clube aparece na lista de clubes ativos
-    let active_clubs = client.get_active_clubs();
+    let active_clubs = client.get_active_clubs(&0u32, &10u32);
     assert_eq!(active_clubs.len(), 1);
-    assert_eq!(active_clubs.get(0).unwrap(), club_id);
+    assert_eq!(active_clubs.get(0).unwrap().id, club_id);
 
     // === PASSO 5-7: CONVITE E ADIÇÃO DE MEMBROS ===
     // Usuário A convida Usuário B e Usuário C para o "Clube Alpha"
@@ -93,14 +105,194 @@ fn test_tc001_create_activate_and_add_members_to_club() {
     // 1. O "Clube Alpha" foi criado com sucesso e aparece na lista de clubes ativos
     let final_club = client.get_club(&club_id);
     assert_eq!(final_club.name, club_name);
-    assert_eq!(final_club.is_active, true);
+    assert!(final_club.is_active);
 
-    let active_clubs_final = client.get_active_clubs();
-    assert!(active_clubs_final.contains(club_id));
+    let active_clubs_final = client.get_active_clubs(&0u32, &10u32);
+    assert!(active_clubs_final.iter().any(|club| club.id == club_id));
 
     // 3. Usuário B e Usuário C aparecem como membros do "Clube Alpha"
-    let members = client.get_members(&club_id);
+    let members = client.get_members(&club_id, &0u32, &10u32);
     assert_eq!(members.len(), 2);
     assert!(members.contains(&member_b));
     assert!(members.contains(&member_c));
 }
+
+#[test]
+fn test_tc001_club_management_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let intruder = Address::generate(&env);
+    let member_b = Address::generate(&env);
+    let club_name = String::from_str(&env, "Clube Alpha");
+
+    // Parâmetros inválidos na criação
+    assert_eq!(
+        client.try_create_club(
+            &organizer,
+            &club_name,
+            &ClubConfig {
+                usdc_per_km: 0i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(30),
+                end_timestamp: common::days(30),
+                limits: RunLimits::default(),
+                max_members: None,
+            },
+        ),
+        Err(Ok(RunClubError::InvalidUsdcPerKm))
+    );
+    assert_eq!(
+        client.try_create_club(
+            &organizer,
+            &club_name,
+            &ClubConfig {
+                usdc_per_km: 1i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(0),
+                end_timestamp: common::days(0),
+                limits: RunLimits::default(),
+                max_members: None,
+            },
+        ),
+        Err(Ok(RunClubError::InvalidDuration))
+    );
+
+    // Clube inexistente
+    assert_eq!(
+        client.try_get_club(&42u64),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+    assert_eq!(
+        client.try_add_member(&42u64, &member_b),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+
+    let club_id = client.create_club(
+        &organizer,
+        &club_name,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+
+    // Apenas o organizador gerencia o clube
+    assert_eq!(
+        client.try_activate(&club_id, &intruder),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    client.add_member(&club_id, &member_b);
+    assert_eq!(
+        client.try_add_member(&club_id, &member_b),
+        Err(Ok(RunClubError::MemberAlreadyExists))
+    );
+    assert_eq!(
+        client.try_remove_member(&club_id, &intruder, &member_b),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    assert_eq!(
+        client.try_remove_member(&club_id, &organizer, &intruder),
+        Err(Ok(RunClubError::MemberNotFound))
+    );
+
+    // Um clube com USDC depositado não pode ser removido
+    common::mint_usdc(&env, &usdc, &organizer, 10i128);
+    client.deposit_usdc(&club_id, &organizer, &10i128);
+    assert_eq!(
+        client.try_remove_club(&club_id, &organizer, &10u32),
+        Err(Ok(RunClubError::ClubHasDeposits))
+    );
+
+    client.remove_member(&club_id, &organizer, &member_b);
+    assert_eq!(client.get_members(&club_id, &0u32, &10u32).len(), 0);
+}
+
+#[test]
+fn test_tc001_members_are_paginated() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+
+    // O organizador também corre no próprio clube
+    client.add_member(&club_id, &organizer);
+    let mut runners = Vec::new(&env);
+    for _ in 0..4 {
+        let runner = Address::generate(&env);
+        client.add_member(&club_id, &runner);
+        runners.push_back(runner);
+    }
+    assert_eq!(client.get_club(&club_id).member_count, 5);
+
+    let organizer_entry = client.get_member(&club_id, &organizer);
+    assert_eq!(organizer_entry.role, MemberRole::Organizer);
+    assert_eq!(organizer_entry.joined_at, 1_000);
+    assert_eq!(
+        client.get_member(&club_id, &runners.get(0).unwrap()).role,
+        MemberRole::Member
+    );
+
+    // Páginas de 2 membros
+    let first = client.get_members(&club_id, &0u32, &2u32);
+    let second = client.get_members(&club_id, &2u32, &2u32);
+    let third = client.get_members(&club_id, &4u32, &2u32);
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 2);
+    assert_eq!(third.len(), 1);
+    assert_eq!(client.get_members(&club_id, &5u32, &2u32).len(), 0);
+
+    // Remover um membro do meio mantém a lista compacta
+    let removed = runners.get(1).unwrap();
+    client.remove_member(&club_id, &organizer, &removed);
+    assert_eq!(client.get_club(&club_id).member_count, 4);
+    assert_eq!(
+        client.try_get_member(&club_id, &removed),
+        Err(Ok(RunClubError::MemberNotFound))
+    );
+
+    let members = client.get_members(&club_id, &0u32, &10u32);
+    assert_eq!(members.len(), 4);
+    assert!(!members.contains(&removed));
+    assert!(members.contains(&organizer));
+    for runner in [runners.get(0), runners.get(2), runners.get(3)] {
+        assert!(members.contains(runner.unwrap()));
+    }
+
+    // Pode voltar ao clube depois de removido
+    client.add_member(&club_id, &removed);
+    assert_eq!(client.get_club(&club_id).member_count, 5);
+}
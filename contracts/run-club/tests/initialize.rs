@@ -0,0 +1,89 @@
+//! Teste TC-007: Configuração do Contrato na Implantação
+//!
+//! Objetivo: Verificar que o contrato é configurado pelo construtor, na mesma
+//! transação que o implanta, e que ninguém consegue configurá-lo depois.
+//!
+//! Pré-condições:
+//! • O USDC e o wasm do token KM já estão no ledger
+//!
+//! Cenário:
+//! 1. O contrato é implantado com o admin, o USDC, o wasm do token KM e a taxa da plataforma
+//! 2. Um terceiro tenta configurar o contrato de novo
+//!
+//! Resultados Esperados:
+//! • A configuração é retornada pelas consultas
+//! • Não existe `initialize` e o construtor não pode ser chamado de novo
+//! • Uma taxa acima de 100% impede a implantação com `InvalidPlatformFee`
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, IntoVal, Symbol, Val, Vec};
+
+mod common;
+
+use run_club::{RunClubContract, RunClubContractClient, RunClubError};
+
+#[test]
+fn test_tc007_constructor_configures_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let usdc = common::create_usdc(&env);
+    let wasm_hash = common::upload_km_token(&env);
+    let contract_id = env.register(
+        RunClubContract,
+        (
+            admin.clone(),
+            usdc.address.clone(),
+            wasm_hash.clone(),
+            250u32,
+        ),
+    );
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_usdc_token(), usdc.address);
+    assert_eq!(client.get_platform_fee(), 250u32);
+
+    // Ninguém consegue reconfigurar, nem mesmo o admin
+    let attacker = Address::generate(&env);
+    let args: Vec<Val> = (attacker, usdc.address.clone(), wasm_hash, 0u32).into_val(&env);
+    for function in ["initialize", "__constructor"] {
+        assert!(env
+            .try_invoke_contract::<(), RunClubError>(
+                &contract_id,
+                &Symbol::new(&env, function),
+                args.clone(),
+            )
+            .is_err());
+    }
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_platform_fee(), 250u32);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_tc007_constructor_rejects_invalid_fee() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let usdc = common::create_usdc(&env);
+    let wasm_hash = common::upload_km_token(&env);
+
+    env.register(RunClubContract, (admin, usdc.address, wasm_hash, 10_001u32));
+}
+
+#[test]
+fn test_tc007_constructor_accepts_full_fee() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let usdc = common::create_usdc(&env);
+    let wasm_hash = common::upload_km_token(&env);
+
+    // 100% ainda é aceito
+    let contract_id = env.register(RunClubContract, (admin, usdc.address, wasm_hash, 10_000u32));
+    let client = RunClubContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_platform_fee(), 10_000u32);
+}
@@ -0,0 +1,169 @@
+//! Teste TC-024: Clubes Privados por Aprovação ou Convite
+//!
+//! Objetivo: Verificar que um clube pode exigir aprovação do organizador ou um
+//! código de convite para aceitar novos membros.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A organiza o "Clube Alpha", aberto por padrão
+//!
+//! Cenário:
+//! 1. Usuário A exige aprovação; Usuários B, C e D pedem para entrar
+//! 2. Usuário A recusa C, aprova B e recusa D
+//! 3. Usuário A passa o clube para convite e entrega um código a Usuário D
+//!
+//! Resultados Esperados:
+//! • Fora da política `Open`, `add_member` é recusado
+//! • Só entra quem teve o pedido aprovado
+//! • Os pedidos pendentes seguem a ordem de chegada, mesmo com decisões fora de ordem
+//! • O convite é guardado como hash, vale uma vez e só com o código certo
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, BytesN, Env,
+};
+
+mod common;
+
+use run_club::{MembershipPolicy, RunClubContractClient, RunClubError, WithdrawalRule};
+
+fn setup<'a>(env: &Env) -> (RunClubContractClient<'a>, u64, Address) {
+    let config = common::club_config(WithdrawalRule::Equal);
+    let (client, _usdc, club_id, organizer) = common::setup_club(env, &config, 0);
+    assert_eq!(
+        client.get_club(&club_id).membership_policy,
+        MembershipPolicy::Open
+    );
+
+    (client, club_id, organizer)
+}
+
+#[test]
+fn test_tc024_approval_required() {
+    let env = Env::default();
+    let (client, club_id, organizer) = setup(&env);
+    let member_b = Address::generate(&env);
+    let member_c = Address::generate(&env);
+    let member_d = Address::generate(&env);
+
+    assert_eq!(
+        client.try_request_to_join(&club_id, &member_b),
+        Err(Ok(RunClubError::MembershipRestricted))
+    );
+    assert_eq!(
+        client.try_set_membership_policy(&club_id, &member_b, &MembershipPolicy::ApprovalRequired),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.set_membership_policy(&club_id, &organizer, &MembershipPolicy::ApprovalRequired);
+
+    assert_eq!(
+        client.try_add_member(&club_id, &member_b),
+        Err(Ok(RunClubError::MembershipRestricted))
+    );
+    client.request_to_join(&club_id, &member_b);
+    client.request_to_join(&club_id, &member_c);
+    client.request_to_join(&club_id, &member_d);
+    assert_eq!(
+        client.try_request_to_join(&club_id, &member_b),
+        Err(Ok(RunClubError::MemberAlreadyExists))
+    );
+    assert_eq!(
+        client.get_join_requests(&club_id, &0u32, &10u32),
+        vec![&env, member_b.clone(), member_c.clone(), member_d.clone()]
+    );
+
+    assert_eq!(
+        client.try_approve_member(&club_id, &member_c, &member_b),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    // Recusar do meio da fila deixa a posição vazia, sem mudar a ordem
+    client.reject_member(&club_id, &organizer, &member_c);
+    assert_eq!(
+        client.get_join_requests(&club_id, &0u32, &10u32),
+        vec![&env, member_b.clone(), member_d.clone()]
+    );
+    assert!(client.get_join_requests(&club_id, &1u32, &1u32).is_empty());
+
+    client.approve_member(&club_id, &organizer, &member_b);
+    assert_eq!(
+        client.get_join_requests(&club_id, &0u32, &1u32),
+        vec![&env, member_d.clone()]
+    );
+    client.reject_member(&club_id, &organizer, &member_d);
+    assert!(client.get_join_requests(&club_id, &0u32, &10u32).is_empty());
+    assert_eq!(
+        client.try_approve_member(&club_id, &organizer, &member_c),
+        Err(Ok(RunClubError::MemberNotFound))
+    );
+
+    let club = client.get_club(&club_id);
+    assert_eq!(club.member_count, 1);
+    assert_eq!(
+        client.get_members(&club_id, &0u32, &10u32),
+        vec![&env, member_b.clone()]
+    );
+
+    // Pedidos depois do fim das inscrições não são aceitos
+    env.ledger().with_mut(|li| {
+        li.timestamp = club.registration_close_timestamp + 1;
+    });
+    assert_eq!(
+        client.try_request_to_join(&club_id, &member_c),
+        Err(Ok(RunClubError::RegistrationClosed))
+    );
+}
+
+#[test]
+fn test_tc024_invite_only() {
+    let env = Env::default();
+    let (client, club_id, organizer) = setup(&env);
+    let member_d = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    client.set_membership_policy(&club_id, &organizer, &MembershipPolicy::InviteOnly);
+    assert_eq!(
+        client.try_add_member(&club_id, &member_d),
+        Err(Ok(RunClubError::MembershipRestricted))
+    );
+
+    // Só o hash do código vai para o contrato
+    let code = Bytes::from_slice(&env, b"alpha-convite-d");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.add_invite(&club_id, &organizer, &code_hash);
+    assert_eq!(
+        client.try_add_invite(&club_id, &organizer, &code_hash),
+        Err(Ok(RunClubError::InvalidInvite))
+    );
+
+    let wrong = Bytes::from_slice(&env, b"alpha-convite-x");
+    assert_eq!(
+        client.try_join_with_invite(&club_id, &outsider, &wrong),
+        Err(Ok(RunClubError::InvalidInvite))
+    );
+
+    client.join_with_invite(&club_id, &member_d, &code);
+    assert_eq!(client.get_club(&club_id).member_count, 1);
+
+    // O convite vale uma única vez
+    assert_eq!(
+        client.try_join_with_invite(&club_id, &outsider, &code),
+        Err(Ok(RunClubError::InvalidInvite))
+    );
+
+    // Convite cancelado antes do uso
+    let code = Bytes::from_slice(&env, b"alpha-convite-e");
+    let code_hash: BytesN<32> = env.crypto().sha256(&code).into();
+    client.add_invite(&club_id, &organizer, &code_hash);
+    client.revoke_invite(&club_id, &organizer, &code_hash);
+    assert_eq!(
+        client.try_join_with_invite(&club_id, &outsider, &code),
+        Err(Ok(RunClubError::InvalidInvite))
+    );
+    assert_eq!(
+        client.try_revoke_invite(&club_id, &organizer, &code_hash),
+        Err(Ok(RunClubError::InvalidInvite))
+    );
+}
@@ -0,0 +1,186 @@
+//! Teste TC-012: Atestações de Corrida por Oráculo
+//!
+//! Objetivo: Verificar que o KM só é creditado com uma atestação assinada por um
+//! oráculo confiável do clube.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado com um oráculo global
+//! • Usuário A criou e financiou o "Clube Alpha" e Usuário B é membro
+//!
+//! Cenário:
+//! 1. Usuário B envia corridas atestadas pelo oráculo global
+//! 2. Atestações adulteradas, repetidas ou de oráculos desconhecidos são enviadas
+//! 3. Usuário A registra um oráculo próprio para o clube
+//!
+//! Resultados Esperados:
+//! • Apenas atestações válidas e inéditas creditam KM
+//! • Uma assinatura inválida aborta a chamada com o erro do host `Error(Crypto, InvalidInput)`
+//! • Um clube com oráculos próprios deixa de aceitar os oráculos globais
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, InvokeError,
+};
+
+mod common;
+
+use common::Oracle;
+use run_club::{RunClubContractClient, RunClubError, WithdrawalRule, KM_UNIT};
+
+fn setup<'a>(env: &Env) -> (RunClubContractClient<'a>, u64, Address, Address) {
+    env.ledger().set_timestamp(10_000);
+
+    let config = common::club_config(WithdrawalRule::Unlimited);
+    let (client, _usdc, club_id, organizer) = common::setup_club(env, &config, 100i128);
+    let runner = Address::generate(env);
+    client.add_member(&club_id, &runner);
+
+    (client, club_id, organizer, runner)
+}
+
+#[test]
+fn test_tc012_attestation_is_verified() {
+    let env = Env::default();
+    let (client, club_id, _organizer, runner) = setup(&env);
+    let oracle = Oracle::default();
+    let oracle_key = oracle.public_key(&env);
+
+    // Corrida válida de 5,4 km: credita 5,4 KM, com precisão de metro
+    let attestation = oracle.attest(&env, &runner, club_id, 5_400);
+    let signature = oracle.sign(&env, &client.address, &attestation);
+    client.add_km_tokens(&attestation, &oracle_key, &signature);
+    assert_eq!(
+        client.get_user_km_tokens(&runner, &club_id),
+        54 * KM_UNIT / 10
+    );
+
+    // A mesma atestação não pode ser usada duas vezes
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::NonceAlreadyUsed))
+    );
+
+    // Distância adulterada depois da assinatura: o `ed25519_verify` aborta a chamada
+    // com um erro do host, não com um `RunClubError`
+    let mut tampered = oracle.attest(&env, &runner, club_id, 3_000);
+    let signature = oracle.sign(&env, &client.address, &tampered);
+    tampered.distance_meters = 30_000;
+    assert_eq!(
+        client.try_add_km_tokens(&tampered, &oracle_key, &signature),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // Assinatura feita para outro deploy do contrato
+    let attestation = oracle.attest(&env, &runner, club_id, 3_000);
+    let signature = oracle.sign(&env, &Address::generate(&env), &attestation);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Err(InvokeError::Abort))
+    );
+
+    // Oráculo que ninguém registrou
+    let rogue = Oracle::new([9; 32]);
+    let attestation = rogue.attest(&env, &runner, club_id, 3_000);
+    let signature = rogue.sign(&env, &client.address, &attestation);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &rogue.public_key(&env), &signature),
+        Err(Ok(RunClubError::UnknownOracle))
+    );
+
+    // Corrida que termina no futuro
+    let mut attestation = oracle.attest(&env, &runner, club_id, 3_000);
+    attestation.end_timestamp = env.ledger().timestamp() + 60;
+    let signature = oracle.sign(&env, &client.address, &attestation);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::InvalidAttestation))
+    );
+
+    // Nada além da primeira corrida foi creditado
+    assert_eq!(
+        client.get_user_km_tokens(&runner, &club_id),
+        54 * KM_UNIT / 10
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_tc012_tampered_attestation_fails_signature_check() {
+    let env = Env::default();
+    let (client, club_id, _organizer, runner) = setup(&env);
+    let oracle = Oracle::default();
+
+    let mut tampered = oracle.attest(&env, &runner, club_id, 3_000);
+    let signature = oracle.sign(&env, &client.address, &tampered);
+    tampered.distance_meters = 30_000;
+    client.add_km_tokens(&tampered, &oracle.public_key(&env), &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn test_tc012_signature_for_another_deploy_fails_signature_check() {
+    let env = Env::default();
+    let (client, club_id, _organizer, runner) = setup(&env);
+    let oracle = Oracle::default();
+
+    let attestation = oracle.attest(&env, &runner, club_id, 3_000);
+    let signature = oracle.sign(&env, &Address::generate(&env), &attestation);
+    client.add_km_tokens(&attestation, &oracle.public_key(&env), &signature);
+}
+
+#[test]
+fn test_tc012_club_oracles_override_global_ones() {
+    let env = Env::default();
+    let (client, club_id, organizer, runner) = setup(&env);
+    let global = Oracle::default();
+    let club_oracle = Oracle::new([11; 32]);
+    let club_oracle_key = club_oracle.public_key(&env);
+
+    assert_eq!(client.get_oracles().len(), 1);
+    assert_eq!(
+        client.try_add_oracle(&global.public_key(&env)),
+        Err(Ok(RunClubError::OracleAlreadyExists))
+    );
+
+    // Só o organizador registra oráculos do clube
+    assert_eq!(
+        client.try_add_club_oracle(&club_id, &runner, &club_oracle_key),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.add_club_oracle(&club_id, &organizer, &club_oracle_key);
+    assert_eq!(client.get_club_oracles(&club_id).len(), 1);
+
+    // O oráculo global deixa de valer para este clube
+    let attestation = global.attest(&env, &runner, club_id, 2_000);
+    let signature = global.sign(&env, &client.address, &attestation);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &global.public_key(&env), &signature),
+        Err(Ok(RunClubError::UnknownOracle))
+    );
+
+    let attestation = club_oracle.attest(&env, &runner, club_id, 2_000);
+    let signature = club_oracle.sign(&env, &client.address, &attestation);
+    client.add_km_tokens(&attestation, &club_oracle_key, &signature);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 2 * KM_UNIT);
+
+    // Sem oráculos próprios, o clube volta a aceitar os globais
+    client.remove_club_oracle(&club_id, &organizer, &club_oracle_key);
+    assert_eq!(
+        client.try_remove_club_oracle(&club_id, &organizer, &club_oracle_key),
+        Err(Ok(RunClubError::OracleNotFound))
+    );
+    common::add_km(&env, &client, club_id, &runner, 1i128);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 3 * KM_UNIT);
+
+    // O admin pode retirar o oráculo global
+    client.remove_oracle(&global.public_key(&env));
+    assert_eq!(client.get_oracles().len(), 0);
+    let (attestation, oracle_key, signature) =
+        common::attest_km(&env, &client, club_id, &runner, 1);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::UnknownOracle))
+    );
+}
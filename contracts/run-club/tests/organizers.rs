@@ -0,0 +1,191 @@
+//! Teste TC-023: Troca de Organizador e Co-organizadores
+//!
+//! Objetivo: Verificar que a organização do clube só muda de mãos em dois passos
+//! (proposta e aceite) e que co-organizadores só fazem o que os seus escopos permitem.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A organiza o "Clube Alpha", com 100 USDC depositados
+//! • Usuário B é membro do clube
+//!
+//! Cenário:
+//! 1. Usuário A propõe Usuário D como organizador, e D aceita
+//! 2. Usuário A dá a Usuário C o escopo de membros e depois o de fechamento
+//! 3. Usuário C recolhe o USDC não resgatado depois do prazo
+//!
+//! Resultados Esperados:
+//! • Só o endereço proposto aceita a organização, e o antigo organizador perde os poderes
+//! • Um co-organizador é barrado fora dos seus escopos
+//! • O USDC recolhido por um co-organizador vai para o organizador
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env,
+};
+
+mod common;
+
+use run_club::{
+    ClubPermission, RemovedKmPolicy, RunClubContractClient, RunClubError, WithdrawalRule,
+};
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    RunClubContractClient<'a>,
+    token::Client<'a>,
+    u64,
+    Address,
+    Address,
+) {
+    let config = common::club_config(WithdrawalRule::Equal);
+    let (client, usdc, club_id, organizer) = common::setup_club(env, &config, 100i128);
+
+    let member = Address::generate(env);
+    client.add_member(&club_id, &member);
+
+    (client, usdc, club_id, organizer, member)
+}
+
+#[test]
+fn test_tc023_two_step_organizer_handover() {
+    let env = Env::default();
+    let (client, _, club_id, organizer, member) = setup(&env);
+    let new_organizer = Address::generate(&env);
+
+    assert_eq!(
+        client.try_propose_organizer(&club_id, &member, &new_organizer),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.propose_organizer(&club_id, &organizer, &new_organizer);
+    assert_eq!(
+        client.get_club(&club_id).pending_organizer,
+        Some(new_organizer.clone())
+    );
+
+    // Só o endereço proposto aceita
+    assert_eq!(
+        client.try_accept_organizer(&club_id, &member),
+        Err(Ok(RunClubError::NotPendingOrganizer))
+    );
+    assert_eq!(client.get_club(&club_id).organizer, organizer);
+
+    client.accept_organizer(&club_id, &new_organizer);
+    let club = client.get_club(&club_id);
+    assert_eq!(club.organizer, new_organizer);
+    assert_eq!(club.pending_organizer, None);
+    assert_eq!(
+        client.try_accept_organizer(&club_id, &new_organizer),
+        Err(Ok(RunClubError::NotPendingOrganizer))
+    );
+
+    let clubs = client.get_user_clubs(&new_organizer, &0u32, &10u32);
+    assert_eq!(clubs.len(), 1);
+    assert!(clubs.get(0).unwrap().is_organizer);
+    assert!(client.get_user_clubs(&organizer, &0u32, &10u32).is_empty());
+
+    // O antigo organizador perde os poderes
+    assert_eq!(
+        client.try_remove_member(&club_id, &organizer, &member),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.remove_member(&club_id, &new_organizer, &member);
+    assert_eq!(client.get_club(&club_id).member_count, 0);
+}
+
+#[test]
+fn test_tc023_co_organizer_scopes() {
+    let env = Env::default();
+    let (client, usdc, club_id, organizer, member) = setup(&env);
+    let co_organizer = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_co_organizer(&club_id, &organizer, &co_organizer, &vec![&env]),
+        Err(Ok(RunClubError::InvalidPermissions))
+    );
+    assert_eq!(
+        client.try_set_co_organizer(
+            &club_id,
+            &organizer,
+            &organizer,
+            &vec![&env, ClubPermission::Funding]
+        ),
+        Err(Ok(RunClubError::InvalidPermissions))
+    );
+    assert_eq!(
+        client.try_set_co_organizer(
+            &club_id,
+            &member,
+            &co_organizer,
+            &vec![&env, ClubPermission::Membership]
+        ),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    client.set_co_organizer(
+        &club_id,
+        &organizer,
+        &co_organizer,
+        &vec![&env, ClubPermission::Membership],
+    );
+    assert_eq!(client.get_co_organizers(&club_id).len(), 1);
+
+    // Escopo de membros, sem o de fundos nem o de fechamento
+    client.set_removed_km_policy(&club_id, &co_organizer, &RemovedKmPolicy::Freeze);
+    common::mint_usdc(&env, &usdc, &co_organizer, 10i128);
+    assert_eq!(
+        client.try_deposit_usdc(&club_id, &co_organizer, &10i128),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    assert_eq!(
+        client.try_set_claim_window(&club_id, &co_organizer, &common::days(7)),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    // Co-organizadores não mexem na equipe
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.try_set_co_organizer(
+            &club_id,
+            &co_organizer,
+            &other,
+            &vec![&env, ClubPermission::Membership]
+        ),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    // Troca de escopos: perde o de membros, ganha o de fechamento
+    client.set_co_organizer(
+        &club_id,
+        &organizer,
+        &co_organizer,
+        &vec![&env, ClubPermission::Settlement],
+    );
+    assert_eq!(client.get_co_organizers(&club_id).len(), 1);
+    assert_eq!(
+        client.try_remove_member(&club_id, &co_organizer, &member),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    // Ninguém correu: o USDC recolhido vai para o organizador
+    let club = client.get_club(&club_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp = club.month_end_timestamp + 1;
+    });
+    let settlement = client.settle_club(&club_id, &10u32).unwrap();
+    env.ledger().with_mut(|li| {
+        li.timestamp = settlement.claim_deadline + 1;
+    });
+    assert_eq!(client.sweep_unclaimed(&club_id, &co_organizer), 100i128);
+    assert_eq!(usdc.balance(&organizer), 100i128);
+    assert_eq!(usdc.balance(&co_organizer), 10i128);
+
+    client.remove_co_organizer(&club_id, &organizer, &co_organizer);
+    assert!(client.get_co_organizers(&club_id).is_empty());
+    assert_eq!(
+        client.try_remove_co_organizer(&club_id, &organizer, &co_organizer),
+        Err(Ok(RunClubError::CoOrganizerNotFound))
+    );
+}
@@ -0,0 +1,145 @@
+//! Teste TC-015: Taxa USDC por KM e Sobra do Pool
+//!
+//! Objetivo: Verificar que a regra "Unlimited" paga km × `usdc_per_km`, limitado ao
+//! pool, e que a sobra volta para o organizador.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A criou um clube "Unlimited" com 100 USDC e a sobra devolvida ao organizador
+//! • Usuário B correu 8 km e Usuário C correu 2 km
+//!
+//! Cenário:
+//! 1. Com 2 USDC por km, Usuário A resgata a sobra e os corredores resgatam
+//! 2. Com 20 USDC por km, o pool não cobre a taxa e é dividido na proporção dos KM
+//! 3. Com KM fracionados, o valor de cada corredor é arredondado para baixo
+//!
+//! Resultados Esperados:
+//! • Cada corredor recebe km × taxa e a sobra (80 USDC) volta para Usuário A
+//! • Quando o pool é insuficiente, ninguém recebe mais que sua fatia e não há sobra
+//! • Com a taxa cabendo no pool, o arredondamento não vira poeira e fica na sobra
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env};
+
+mod common;
+
+use run_club::{ClubConfig, RunClubContractClient, RunClubError, SurplusRule, WithdrawalRule};
+
+fn setup<'a>(
+    env: &Env,
+    usdc_per_km: i128,
+) -> (
+    RunClubContractClient<'a>,
+    TokenClient<'a>,
+    u64,
+    Address,
+    Address,
+    Address,
+) {
+    let config = ClubConfig {
+        usdc_per_km,
+        surplus_rule: SurplusRule::ReturnToOrganizer,
+        ..common::club_config(WithdrawalRule::Unlimited)
+    };
+    let (client, usdc, club_id, organizer) = common::setup_club(env, &config, 0);
+    client.activate(&club_id, &organizer);
+    common::mint_usdc(env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+
+    let member_b = Address::generate(env);
+    let member_c = Address::generate(env);
+    client.add_member(&club_id, &member_b);
+    client.add_member(&club_id, &member_c);
+
+    common::add_km(env, &client, club_id, &member_b, 8i128);
+    common::add_km(env, &client, club_id, &member_c, 2i128);
+
+    (client, usdc, club_id, organizer, member_b, member_c)
+}
+
+#[test]
+fn test_tc015_rate_pays_per_km_and_returns_surplus() {
+    let env = Env::default();
+    let (client, usdc, club_id, organizer, member_b, member_c) = setup(&env, 2i128);
+
+    // 10 km × 2 USDC = 20 USDC; sobram 80
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 16i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 4i128);
+    assert_eq!(client.get_club_surplus(&club_id), 80i128);
+
+    // Os KM ainda podem mudar antes do fim do período
+    assert_eq!(
+        client.try_withdraw_surplus(&club_id, &organizer),
+        Err(Ok(RunClubError::ClubPeriodNotEnded))
+    );
+
+    common::end_period(&env, &client, club_id);
+
+    assert_eq!(
+        client.try_withdraw_surplus(&club_id, &member_b),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    assert_eq!(client.withdraw_surplus(&club_id, &organizer), 80i128);
+    assert_eq!(usdc.balance(&organizer), 80i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 20i128);
+    assert_eq!(
+        client.try_withdraw_surplus(&club_id, &organizer),
+        Err(Ok(RunClubError::NoSurplus))
+    );
+
+    // Os corredores continuam recebendo km × taxa
+    let destination = Address::generate(&env);
+    assert_eq!(
+        client.redeem_usdc(&club_id, &member_b, &destination),
+        16i128
+    );
+    assert_eq!(client.redeem_usdc(&club_id, &member_c, &destination), 4i128);
+    assert_eq!(usdc.balance(&destination), 20i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+}
+
+#[test]
+fn test_tc015_rate_is_capped_by_the_pool() {
+    let env = Env::default();
+    let (client, _usdc, club_id, organizer, member_b, member_c) = setup(&env, 20i128);
+
+    // 10 km × 20 USDC = 200 USDC, mas o pool só tem 100
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 80i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 20i128);
+    assert_eq!(client.get_club_surplus(&club_id), 0i128);
+
+    common::end_period(&env, &client, club_id);
+    assert_eq!(
+        client.try_withdraw_surplus(&club_id, &organizer),
+        Err(Ok(RunClubError::NoSurplus))
+    );
+
+    let destination = Address::generate(&env);
+    assert_eq!(
+        client.redeem_usdc(&club_id, &member_c, &destination),
+        20i128
+    );
+    assert_eq!(
+        client.redeem_usdc(&club_id, &member_b, &destination),
+        80i128
+    );
+}
+
+#[test]
+fn test_tc015_rate_rounding_stays_in_the_surplus() {
+    let env = Env::default();
+    let (client, _usdc, club_id, organizer, member_b, member_c) = setup(&env, 2i128);
+
+    // Mais 0,25 km para cada um: B tem 8,25 km (16,5 USDC) e C 2,25 km (4,5 USDC)
+    common::add_meters(&env, &client, club_id, &member_b, 250);
+    common::add_meters(&env, &client, club_id, &member_c, 250);
+
+    common::end_period(&env, &client, club_id);
+    let settlement = client.settle_club(&club_id, &10u32).unwrap();
+    assert_eq!(settlement.dust, 0i128);
+    assert_eq!(settlement.total_payout, 20i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 16i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 4i128);
+    assert_eq!(client.withdraw_surplus(&club_id, &organizer), 80i128);
+}
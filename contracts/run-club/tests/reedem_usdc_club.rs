@@ -22,22 +22,28 @@
 
 #![cfg(test)]
 
-use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, String};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::TokenClient,
+    Address, Env, String,
+};
 
-use run_club::{RunClubContract, RunClubContractClient, WithdrawalRule};
+mod common;
+
+use run_club::{
+    ClubConfig, RunClubContractClient, RunClubError, RunLimits, SurplusRule, WithdrawalRule,
+    KM_UNIT,
+};
 
 #[test]
 fn test_tc005_redeem_km_tokens_for_usdc_equal_distribution() {
     let env = Env::default();
     env.mock_all_auths();
 
-    // Registrar o contrato
-    let contract_id = env.register(RunClubContract, ());
+    // Implantar o contrato já configurado
+    let (contract_id, usdc) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
 
-    // Inicializar o contrato
-    client.initialize();
-
     // === CONFIGURAÇÃO DOS USUÁRIOS ===
     let organizer = Address::generate(&env);
     let member_b = Address::generate(&env);
@@ -48,20 +54,28 @@ fn test_tc005_redeem_km_tokens_for_usdc_equal_distribution() {
     let club_name = String::from_str(&env, "Clube Alpha");
     let usdc_per_km = 1i128;
     let withdrawal_rule = WithdrawalRule::Equal; // Regra de "Distribuição Igual"
-    let duration_days = 30u32;
+    let duration_days = 30u64;
 
     // Criar o clube
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &usdc_per_km,
-        &withdrawal_rule,
-        &duration_days,
+        &ClubConfig {
+            usdc_per_km,
+            withdrawal_rule,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(duration_days),
+            end_timestamp: common::days(duration_days),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     // Ativar o clube e depositar 100 USDC
     client.activate(&club_id, &organizer);
     let usdc_amount = 100i128;
+    common::mint_usdc(&env, &usdc, &organizer, usdc_amount);
     client.deposit_usdc(&club_id, &organizer, &usdc_amount);
 
     // Adicionar membros ao clube
@@ -69,23 +83,23 @@ fn test_tc005_redeem_km_tokens_for_usdc_equal_distribution() {
     client.add_member(&club_id, &member_c);
 
     // Usuário B ganha 5 KM tokens
-    client.add_km_tokens(&club_id, &member_b, &5i128);
-    
+    common::add_km(&env, &client, club_id, &member_b, 5i128);
+
     // Usuário C não ganha tokens KM (permanece com 0)
 
     // === VERIFICAÇÃO DAS PRÉ-CONDIÇÕES ===
     let club = client.get_club(&club_id);
-    assert_eq!(club.is_active, true);
+    assert!(club.is_active);
     assert_eq!(club.usdc_deposited, 100i128);
     assert_eq!(club.withdrawal_rule, WithdrawalRule::Equal);
 
     let member_b_km = client.get_user_km_tokens(&member_b, &club_id);
     let member_c_km = client.get_user_km_tokens(&member_c, &club_id);
-    assert_eq!(member_b_km, 5i128);
+    assert_eq!(member_b_km, 5 * KM_UNIT);
     assert_eq!(member_c_km, 0i128);
 
     // Verificar que o período ainda não terminou
-    assert_eq!(client.is_club_period_ended(&club_id), false);
+    assert!(!client.is_club_period_ended(&club_id));
 
     // === SIMULAR O FIM DO PERÍODO DO CLUBE ===
     // Avançar o tempo para simular o fim do período
@@ -94,15 +108,15 @@ fn test_tc005_redeem_km_tokens_for_usdc_equal_distribution() {
     });
 
     // Verificar que o período terminou
-    assert_eq!(client.is_club_period_ended(&club_id), true);
+    assert!(client.is_club_period_ended(&club_id));
 
     // === PASSO 1-4: USUÁRIO B RESGATA SEUS KM TOKENS ===
-    
+
     // Verificar informações de resgate antes do resgate
     let (km_tokens, usdc_reward, period_ended) = client.get_redemption_info(&club_id, &member_b);
-    assert_eq!(km_tokens, 5i128);
-    assert_eq!(period_ended, true);
-    
+    assert_eq!(km_tokens, 5 * KM_UNIT);
+    assert!(period_ended);
+
     // Com regra "Equal" e apenas Usuário B tendo tokens, ele deve receber todo o USDC
     assert_eq!(usdc_reward, 100i128);
 
@@ -117,11 +131,17 @@ fn test_tc005_redeem_km_tokens_for_usdc_equal_distribution() {
     // 2. Os 5 KM tokens do Usuário B foram queimados/zerados
     let member_b_km_after = client.get_user_km_tokens(&member_b, &club_id);
     assert_eq!(member_b_km_after, 0i128);
+    let km_token = TokenClient::new(&env, &club.token_address);
+    assert_eq!(km_token.balance(&member_b), 0i128);
 
     // 3. O pool de USDC do clube foi reduzido
     let club_after = client.get_club(&club_id);
     assert_eq!(club_after.usdc_deposited, 0i128); // Todo o USDC foi resgatado
 
+    // 4. O USDC foi de fato transferido do contrato para o destino
+    assert_eq!(usdc.balance(&destination_address), 100i128);
+    assert_eq!(usdc.balance(&contract_id), club_after.usdc_deposited);
+
     // === PÓS-CONDIÇÕES ===
     // Usuário B recebeu o USDC correspondente (verificado pelo valor retornado)
     // Os KM tokens do Usuário B foram zerados (verificado acima)
@@ -138,9 +158,8 @@ fn test_tc005_redeem_with_multiple_members_equal_distribution() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(RunClubContract, ());
+    let (contract_id, usdc) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
-    client.initialize();
 
     let organizer = Address::generate(&env);
     let member_b = Address::generate(&env);
@@ -153,19 +172,27 @@ fn test_tc005_redeem_with_multiple_members_equal_distribution() {
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &1i128,
-        &WithdrawalRule::Equal,
-        &30u32,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     client.activate(&club_id, &organizer);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
     client.deposit_usdc(&club_id, &organizer, &100i128);
     client.add_member(&club_id, &member_b);
     client.add_member(&club_id, &member_c);
 
     // Ambos os membros ganham tokens KM (quantidades diferentes)
-    client.add_km_tokens(&club_id, &member_b, &5i128);
-    client.add_km_tokens(&club_id, &member_c, &3i128);
+    common::add_km(&env, &client, club_id, &member_b, 5i128);
+    common::add_km(&env, &client, club_id, &member_c, 3i128);
 
     // Simular fim do período
     let club = client.get_club(&club_id);
@@ -193,6 +220,11 @@ fn test_tc005_redeem_with_multiple_members_equal_distribution() {
     // Verificar que o pool foi esvaziado
     let final_club = client.get_club(&club_id);
     assert_eq!(final_club.usdc_deposited, 0i128);
+
+    // Verificar os saldos reais de USDC
+    assert_eq!(usdc.balance(&destination_b), 50i128);
+    assert_eq!(usdc.balance(&destination_c), 50i128);
+    assert_eq!(usdc.balance(&contract_id), 0i128);
 }
 
 #[test]
@@ -201,9 +233,8 @@ fn test_tc005_redeem_with_unlimited_distribution() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(RunClubContract, ());
+    let (contract_id, usdc) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
-    client.initialize();
 
     let organizer = Address::generate(&env);
     let member_b = Address::generate(&env);
@@ -215,19 +246,27 @@ fn test_tc005_redeem_with_unlimited_distribution() {
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &1i128,
-        &WithdrawalRule::Unlimited,
-        &30u32,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Unlimited,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     client.activate(&club_id, &organizer);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
     client.deposit_usdc(&club_id, &organizer, &100i128);
     client.add_member(&club_id, &member_b);
     client.add_member(&club_id, &member_c);
 
     // Member B: 8 KM, Member C: 2 KM (total: 10 KM)
-    client.add_km_tokens(&club_id, &member_b, &8i128);
-    client.add_km_tokens(&club_id, &member_c, &2i128);
+    common::add_km(&env, &client, club_id, &member_b, 8i128);
+    common::add_km(&env, &client, club_id, &member_c, 2i128);
 
     // Simular fim do período
     let club = client.get_club(&club_id);
@@ -251,18 +290,80 @@ fn test_tc005_redeem_with_unlimited_distribution() {
     assert_eq!(client.get_user_km_tokens(&member_b, &club_id), 0i128);
     let club_after = client.get_club(&club_id);
     assert_eq!(club_after.usdc_deposited, 20i128); // Restam 20 USDC para Member C
+    assert_eq!(usdc.balance(&destination_b), 80i128);
+    assert_eq!(usdc.balance(&contract_id), club_after.usdc_deposited);
+}
+
+#[test]
+fn test_tc005_fractional_km_rewards_round_down() {
+    // Teste adicional: KM com precisão de metro e recompensa arredondada para baixo,
+    // com a poeira distribuída no fechamento
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let member_b = Address::generate(&env);
+    let member_c = Address::generate(&env);
+
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Epsilon"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Unlimited,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    client.activate(&club_id, &organizer);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &member_b);
+    client.add_member(&club_id, &member_c);
+
+    // Member B: 5,3 km, Member C: 2,7 km (total: 8 km)
+    common::add_meters(&env, &client, club_id, &member_b, 5_300);
+    common::add_meters(&env, &client, club_id, &member_c, 2_700);
+    assert_eq!(
+        client.get_user_km_tokens(&member_b, &club_id),
+        53 * KM_UNIT / 10
+    );
+    assert_eq!(client.get_total_km_tokens(&club_id), 8 * KM_UNIT);
+
+    let club = client.get_club(&club_id);
+    let km_token = TokenClient::new(&env, &club.token_address);
+    assert_eq!(km_token.balance(&member_c), 27 * KM_UNIT / 10);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = club.month_end_timestamp + 1;
+    });
+
+    // Member B: 5,3/8 * 100 = 66,25 -> 66 USDC
+    // Member C: 2,7/8 * 100 = 33,75 -> 33 USDC
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 66i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 33i128);
+
+    // No fechamento a poeira (1 USDC) vai para o primeiro da lista que recebe algo
+    client.settle_club(&club_id, &10u32);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 67i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 33i128);
 }
 
 #[test]
-#[should_panic(expected = "Club period has not ended yet")]
 fn test_tc005_cannot_redeem_before_period_ends() {
     // Teste adicional: não pode resgatar antes do período terminar
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(RunClubContract, ());
+    let (contract_id, usdc) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
-    client.initialize();
 
     let organizer = Address::generate(&env);
     let member_b = Address::generate(&env);
@@ -272,30 +373,37 @@ fn test_tc005_cannot_redeem_before_period_ends() {
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &1i128,
-        &WithdrawalRule::Equal,
-        &30u32,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     client.activate(&club_id, &organizer);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
     client.deposit_usdc(&club_id, &organizer, &100i128);
     client.add_member(&club_id, &member_b);
-    client.add_km_tokens(&club_id, &member_b, &5i128);
+    common::add_km(&env, &client, club_id, &member_b, 5i128);
 
     // Tentar resgatar antes do período terminar deve falhar
-    client.redeem_usdc(&club_id, &member_b, &destination);
+    let result = client.try_redeem_usdc(&club_id, &member_b, &destination);
+    assert_eq!(result, Err(Ok(RunClubError::ClubPeriodNotEnded)));
 }
 
 #[test]
-#[should_panic(expected = "User has no KM tokens to redeem")]
 fn test_tc005_cannot_redeem_without_km_tokens() {
     // Teste adicional: não pode resgatar sem tokens KM
     let env = Env::default();
     env.mock_all_auths();
 
-    let contract_id = env.register(RunClubContract, ());
+    let (contract_id, usdc) = common::register(&env);
     let client = RunClubContractClient::new(&env, &contract_id);
-    client.initialize();
 
     let organizer = Address::generate(&env);
     let member_b = Address::generate(&env);
@@ -305,12 +413,20 @@ fn test_tc005_cannot_redeem_without_km_tokens() {
     let club_id = client.create_club(
         &organizer,
         &club_name,
-        &1i128,
-        &WithdrawalRule::Equal,
-        &30u32,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
     );
 
     client.activate(&club_id, &organizer);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
     client.deposit_usdc(&club_id, &organizer, &100i128);
     client.add_member(&club_id, &member_b);
     // Não adicionar tokens KM para o membro
@@ -322,6 +438,55 @@ fn test_tc005_cannot_redeem_without_km_tokens() {
     });
 
     // Tentar resgatar sem tokens KM deve falhar
-    client.redeem_usdc(&club_id, &member_b, &destination);
+    let result = client.try_redeem_usdc(&club_id, &member_b, &destination);
+    assert_eq!(result, Err(Ok(RunClubError::NoKmTokens)));
 }
 
+#[test]
+fn test_tc005_cannot_redeem_after_moving_km_tokens_away() {
+    // Teste adicional: os tokens KM precisam estar na carteira no momento do resgate
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let member_b = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let destination = Address::generate(&env);
+
+    let club_name = String::from_str(&env, "Clube Zeta");
+    let club_id = client.create_club(
+        &organizer,
+        &club_name,
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &member_b);
+    common::add_km(&env, &client, club_id, &member_b, 5i128);
+
+    // Usuário B transfere parte dos seus tokens KM para fora do clube
+    let club = client.get_club(&club_id);
+    let km_token = TokenClient::new(&env, &club.token_address);
+    km_token.transfer(&member_b, &outsider, &(2 * KM_UNIT));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = club.month_end_timestamp + 1;
+    });
+
+    let result = client.try_redeem_usdc(&club_id, &member_b, &destination);
+    assert_eq!(result, Err(Ok(RunClubError::InsufficientBalance)));
+    assert_eq!(usdc.balance(&destination), 0i128);
+}
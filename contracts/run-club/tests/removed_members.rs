@@ -0,0 +1,166 @@
+//! Teste TC-021: KM e Resgate de Membros Removidos
+//!
+//! Objetivo: Verificar que só membros do clube recebem KM e resgatam USDC, e que os
+//! KM de quem é removido seguem a política do clube (perdidos ou congelados).
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • "Clube Alpha" tem 100 USDC e regra de "Distribuição Igual"
+//! • Usuário B correu 5 km e Usuário C correu 3 km
+//!
+//! Cenário:
+//! 1. Usuário A remove Usuário C antes ou depois do fechamento
+//! 2. Usuário C tenta correr e resgatar
+//! 3. Com a política `Freeze`, Usuário C volta ao clube antes do fim do período
+//!
+//! Resultados Esperados:
+//! • Quem não é membro não recebe KM nem resgata
+//! • Antes do fechamento, `Forfeit` zera os KM de C e retira os seus tokens KM
+//! • Depois do fechamento, C mantém o valor fixado e pode resgatá-lo; a remoção não
+//!   aumenta a sobra do organizador
+//! • `Freeze` guarda os KM de C fora do fechamento e os devolve quando ele volta
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token, Address, Env};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RemovedKmPolicy, RunClubContractClient, RunClubError, SurplusRule, WithdrawalRule,
+    KM_UNIT,
+};
+
+fn setup<'a>(env: &Env) -> (RunClubContractClient<'a>, u64, [Address; 3]) {
+    setup_with(env, WithdrawalRule::Equal, SurplusRule::ProRata, 1)
+}
+
+/// Clube com 100 USDC em que B correu 5 km e C correu 3 km
+fn setup_with<'a>(
+    env: &Env,
+    withdrawal_rule: WithdrawalRule,
+    surplus_rule: SurplusRule,
+    usdc_per_km: i128,
+) -> (RunClubContractClient<'a>, u64, [Address; 3]) {
+    let config = ClubConfig {
+        usdc_per_km,
+        surplus_rule,
+        ..common::club_config(withdrawal_rule)
+    };
+    let (client, _usdc, club_id, organizer) = common::setup_club(env, &config, 100i128);
+
+    let member_b = Address::generate(env);
+    let member_c = Address::generate(env);
+    for (member, km) in [(&member_b, 5i128), (&member_c, 3)] {
+        client.add_member(&club_id, member);
+        common::add_km(env, &client, club_id, member, km);
+    }
+
+    (client, club_id, [organizer, member_b, member_c])
+}
+
+#[test]
+fn test_tc021_removed_member_forfeits_km() {
+    let env = Env::default();
+    let (client, club_id, [organizer, member_b, member_c]) = setup(&env);
+    assert_eq!(
+        client.get_club(&club_id).removed_km_policy,
+        RemovedKmPolicy::Forfeit
+    );
+
+    // C já passou 1 km de tokens para B; o clawback leva só o que ficou com C
+    let km_token = token::Client::new(&env, &client.get_club(&club_id).token_address);
+    km_token.transfer(&member_c, &member_b, &KM_UNIT);
+
+    client.remove_member(&club_id, &organizer, &member_c);
+    assert_eq!(client.get_user_km_tokens(&member_c, &club_id), 0i128);
+    assert_eq!(client.get_total_km_tokens(&club_id), 5 * KM_UNIT);
+    assert_eq!(km_token.balance(&member_c), 0i128);
+    assert_eq!(km_token.balance(&member_b), 6 * KM_UNIT);
+
+    // Sem ser membro, C não recebe KM
+    let (attestation, oracle_key, signature) =
+        common::attest_km(&env, &client, club_id, &member_c, 2);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &oracle_key, &signature),
+        Err(Ok(RunClubError::NotMember))
+    );
+
+    common::end_period(&env, &client, club_id);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 0i128);
+    assert_eq!(
+        client.try_redeem_usdc(&club_id, &member_c, &member_c),
+        Err(Ok(RunClubError::NotMember))
+    );
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 100i128);
+    assert_eq!(client.get_settlement(&club_id).eligible_members, 1);
+}
+
+#[test]
+fn test_tc021_removed_member_keeps_settled_payout() {
+    let env = Env::default();
+    let (client, club_id, [organizer, member_b, member_c]) = setup(&env);
+
+    common::end_period(&env, &client, club_id);
+    client.settle_club(&club_id, &10u32);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 50i128);
+
+    // Removido depois do fechamento, C mantém o valor fixado e os tokens KM
+    client.remove_member(&club_id, &organizer, &member_c);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 50i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 50i128);
+    assert_eq!(client.get_settlement(&club_id).total_payout, 100i128);
+
+    assert_eq!(client.redeem_usdc(&club_id, &member_c, &member_c), 50i128);
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 50i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+}
+
+#[test]
+fn test_tc021_removal_after_settlement_does_not_free_surplus() {
+    let env = Env::default();
+    // 10 USDC por km: B fica com 50, C com 30 e sobram 20
+    let (client, club_id, [organizer, member_b, member_c]) = setup_with(
+        &env,
+        WithdrawalRule::Unlimited,
+        SurplusRule::ReturnToOrganizer,
+        10,
+    );
+
+    common::end_period(&env, &client, club_id);
+    client.settle_club(&club_id, &10u32);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 30i128);
+
+    client.remove_member(&club_id, &organizer, &member_c);
+    assert_eq!(client.withdraw_surplus(&club_id, &organizer), 20i128);
+    assert_eq!(client.redeem_usdc(&club_id, &member_c, &member_c), 30i128);
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 50i128);
+}
+
+#[test]
+fn test_tc021_removed_member_km_are_frozen() {
+    let env = Env::default();
+    let (client, club_id, [organizer, member_b, member_c]) = setup(&env);
+
+    assert_eq!(
+        client.try_set_removed_km_policy(&club_id, &member_b, &RemovedKmPolicy::Freeze),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.set_removed_km_policy(&club_id, &organizer, &RemovedKmPolicy::Freeze);
+
+    // Congelados: os KM continuam lá, mas não contam na divisão
+    client.remove_member(&club_id, &organizer, &member_c);
+    assert_eq!(client.get_user_km_tokens(&member_c, &club_id), 3 * KM_UNIT);
+    assert_eq!(client.get_total_km_tokens(&club_id), 5 * KM_UNIT);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_c), 0i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 100i128);
+
+    // De volta ao clube, C recupera os KM congelados
+    client.add_member(&club_id, &member_c);
+    assert_eq!(client.get_user_km_tokens(&member_c, &club_id), 3 * KM_UNIT);
+    assert_eq!(client.get_total_km_tokens(&club_id), 8 * KM_UNIT);
+
+    common::end_period(&env, &client, club_id);
+    assert_eq!(client.redeem_usdc(&club_id, &member_c, &member_c), 50i128);
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 50i128);
+}
@@ -0,0 +1,114 @@
+//! Teste TC-013: Histórico de Corridas
+//!
+//! Objetivo: Verificar que cada corrida creditada fica registrada e que a mesma
+//! corrida não pode ser enviada duas vezes ao clube.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A criou e financiou o "Clube Alpha" e Usuário B é membro
+//!
+//! Cenário:
+//! 1. Usuário B envia três corridas
+//! 2. Usuário B reenvia uma corrida já creditada, com nova atestação
+//!
+//! Resultados Esperados:
+//! • O histórico lista as corridas em ordem, de forma paginada
+//! • O reenvio é rejeitado com `DuplicateRun` e não credita KM
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+mod common;
+
+use common::Oracle;
+use run_club::{
+    ClubConfig, RunClubContractClient, RunClubError, RunLimits, SurplusRule, WithdrawalRule,
+    KM_UNIT,
+};
+
+#[test]
+fn test_tc013_runs_are_recorded_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(100_000);
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Unlimited,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &runner);
+
+    let oracle = Oracle::default();
+    let oracle_key = oracle.public_key(&env);
+    let mut run_ids = [[0u8; 32]; 3];
+    for (i, meters) in [5_000u64, 10_000, 3_000].iter().enumerate() {
+        run_ids[i] = [i as u8 + 1; 32];
+        let mut attestation = oracle.attest(&env, &runner, club_id, *meters);
+        attestation.run_id = BytesN::from_array(&env, &run_ids[i]);
+        let signature = oracle.sign(&env, &contract_id, &attestation);
+        client.add_km_tokens(&attestation, &oracle_key, &signature);
+
+        env.ledger().with_mut(|li| li.timestamp += 3_600);
+    }
+    assert_eq!(client.get_user_run_count(&club_id, &runner), 3);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 18 * KM_UNIT);
+
+    // Histórico paginado, da corrida mais antiga para a mais recente
+    let first_page = client.get_user_runs(&club_id, &runner, &0u32, &2u32);
+    assert_eq!(first_page.len(), 2);
+    let first = first_page.get(0).unwrap();
+    assert_eq!(first.run_id, BytesN::from_array(&env, &run_ids[0]));
+    assert_eq!(first.distance_meters, 5_000);
+    assert_eq!(first.km, 5 * KM_UNIT);
+    assert_eq!(first.end_timestamp, 100_000);
+    assert_eq!(first.start_timestamp, 100_000 - 1_800);
+    assert_eq!(first.recorded_at, 100_000);
+    assert_eq!(first_page.get(1).unwrap().km, 10 * KM_UNIT);
+
+    let second_page = client.get_user_runs(&club_id, &runner, &2u32, &2u32);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(
+        second_page.get(0).unwrap().run_id,
+        BytesN::from_array(&env, &run_ids[2])
+    );
+
+    // Mesma corrida com uma atestação nova (outro nonce)
+    let mut replay = oracle.attest(&env, &runner, club_id, 10_000);
+    replay.run_id = BytesN::from_array(&env, &run_ids[1]);
+    let signature = oracle.sign(&env, &contract_id, &replay);
+    assert_eq!(
+        client.try_add_km_tokens(&replay, &oracle_key, &signature),
+        Err(Ok(RunClubError::DuplicateRun))
+    );
+    assert_eq!(client.get_user_run_count(&club_id, &runner), 3);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 18 * KM_UNIT);
+
+    // Outro corredor não tem corridas
+    let other = Address::generate(&env);
+    assert_eq!(
+        client.get_user_runs(&club_id, &other, &0u32, &10u32).len(),
+        0
+    );
+}
@@ -0,0 +1,182 @@
+//! Teste TC-014: Limites de Plausibilidade das Corridas
+//!
+//! Objetivo: Verificar que o clube rejeita corridas fora dos limites configurados
+//! pelo organizador.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A criou o "Clube Alpha" com ritmo entre 3:00 e 10:00 min/km, no máximo
+//!   30 km por corrida, 40 km por dia e 10 minutos de duração mínima
+//! • Usuário B é membro do clube
+//!
+//! Cenário:
+//! 1. Usuário B envia corridas rápidas demais, lentas demais, longas e curtas demais
+//! 2. Usuário B ultrapassa o limite diário e corre de novo no dia seguinte
+//!
+//! Resultados Esperados:
+//! • Cada corrida implausível é rejeitada com um erro específico
+//! • O limite diário volta a zero no dia seguinte
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+mod common;
+
+use common::Oracle;
+use run_club::{
+    ClubConfig, RunAttestation, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule, KM_UNIT,
+};
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn limits() -> RunLimits {
+    RunLimits {
+        min_pace_secs_per_km: 180,
+        max_pace_secs_per_km: 600,
+        max_distance_meters: 30_000,
+        max_daily_meters: 40_000,
+        min_duration_secs: 600,
+    }
+}
+
+/// Atestação de uma corrida de `meters` metros que durou `duration` segundos
+fn run(
+    env: &Env,
+    client: &RunClubContractClient,
+    runner: &Address,
+    club_id: u64,
+    meters: u64,
+    duration: u64,
+) -> (RunAttestation, BytesN<32>, BytesN<64>) {
+    let oracle = Oracle::default();
+    let mut attestation = oracle.attest(env, runner, club_id, meters);
+    attestation.start_timestamp = attestation.end_timestamp - duration;
+    let signature = oracle.sign(env, &client.address, &attestation);
+    (attestation, oracle.public_key(env), signature)
+}
+
+#[test]
+fn test_tc014_implausible_runs_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(DAY + 12 * 60 * 60);
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Unlimited,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: limits(),
+            max_members: None,
+        },
+    );
+    assert_eq!(client.get_club(&club_id).limits, limits());
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &runner);
+
+    let cases = [
+        (10_000, 20 * 60, RunClubError::RunTooFast), // 2:00 min/km
+        (5_000, 2 * 60 * 60, RunClubError::RunTooSlow), // 24:00 min/km
+        (35_000, 3 * 60 * 60, RunClubError::RunTooLong),
+        (1_000, 5 * 60, RunClubError::RunTooShort),
+    ];
+    for (meters, duration, error) in cases {
+        let (attestation, key, signature) = run(&env, &client, &runner, club_id, meters, duration);
+        assert_eq!(
+            client.try_add_km_tokens(&attestation, &key, &signature),
+            Err(Ok(error))
+        );
+    }
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 0i128);
+
+    // 10 km em 1h e 25 km em 2h30 (6:00 min/km): 35 km no dia
+    let (attestation, key, signature) = run(&env, &client, &runner, club_id, 10_000, 3_600);
+    client.add_km_tokens(&attestation, &key, &signature);
+    let (attestation, key, signature) = run(&env, &client, &runner, club_id, 25_000, 9_000);
+    client.add_km_tokens(&attestation, &key, &signature);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 35 * KM_UNIT);
+
+    // Mais 10 km no mesmo dia passam do limite diário de 40 km
+    let (attestation, key, signature) = run(&env, &client, &runner, club_id, 10_000, 3_600);
+    assert_eq!(
+        client.try_add_km_tokens(&attestation, &key, &signature),
+        Err(Ok(RunClubError::DailyLimitExceeded))
+    );
+
+    // No dia seguinte o limite recomeça
+    env.ledger().with_mut(|li| li.timestamp += DAY);
+    let (attestation, key, signature) = run(&env, &client, &runner, club_id, 10_000, 3_600);
+    client.add_km_tokens(&attestation, &key, &signature);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 45 * KM_UNIT);
+}
+
+#[test]
+fn test_tc014_inconsistent_limits_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let name = String::from_str(&env, "Clube Alpha");
+
+    // Ritmo mínimo mais lento que o máximo
+    let mut bad_pace = limits();
+    bad_pace.min_pace_secs_per_km = 700;
+    assert_eq!(
+        client.try_create_club(
+            &organizer,
+            &name,
+            &ClubConfig {
+                usdc_per_km: 1i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(30),
+                end_timestamp: common::days(30),
+                limits: bad_pace,
+                max_members: None,
+            },
+        ),
+        Err(Ok(RunClubError::InvalidRunLimits))
+    );
+
+    // Uma corrida permitida maior que o limite do dia
+    let mut bad_distance = limits();
+    bad_distance.max_distance_meters = 50_000;
+    assert_eq!(
+        client.try_create_club(
+            &organizer,
+            &name,
+            &ClubConfig {
+                usdc_per_km: 1i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(30),
+                end_timestamp: common::days(30),
+                limits: bad_distance,
+                max_members: None,
+            },
+        ),
+        Err(Ok(RunClubError::InvalidRunLimits))
+    );
+}
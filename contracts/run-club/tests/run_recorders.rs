@@ -0,0 +1,152 @@
+//! Teste TC-022: Quem Pode Creditar Quilômetros
+//!
+//! Objetivo: Verificar que só um registrador do clube, ou o corredor com a
+//! co-assinatura de um oráculo, credita corridas, e que a autorização cobre
+//! exatamente a corrida enviada.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado e o "Clube Alpha" está ativo
+//! • Usuário B é membro do clube
+//! • Usuário A registrou o serviço de backend como registrador do clube
+//!
+//! Cenário:
+//! 1. O backend envia uma corrida de Usuário B
+//! 2. Um endereço que não é registrador tenta enviar outra corrida
+//! 3. O backend tenta reaproveitar uma autorização para outra corrida
+//!
+//! Resultados Esperados:
+//! • A corrida enviada pelo registrador é creditada sem assinatura do oráculo
+//! • Quem não é registrador não credita corridas
+//! • A assinatura vale só para a atestação exata que foi autorizada
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke},
+    Address, Env, IntoVal, Symbol, Val, Vec,
+};
+
+mod common;
+
+use run_club::{RunClubContractClient, RunClubError, WithdrawalRule, KM_UNIT};
+
+fn setup<'a>(env: &Env) -> (RunClubContractClient<'a>, u64, Address, Address) {
+    let config = common::club_config(WithdrawalRule::Equal);
+    let (client, _usdc, club_id, organizer) = common::setup_club(env, &config, 100i128);
+
+    let runner = Address::generate(env);
+    client.add_member(&club_id, &runner);
+
+    (client, club_id, organizer, runner)
+}
+
+#[test]
+fn test_tc022_recorder_credits_runs() {
+    let env = Env::default();
+    let (client, club_id, organizer, runner) = setup(&env);
+    let backend = Address::generate(&env);
+
+    assert_eq!(
+        client.try_add_recorder(&club_id, &runner, &backend),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+    client.add_recorder(&club_id, &organizer, &backend);
+    assert_eq!(
+        client.try_add_recorder(&club_id, &organizer, &backend),
+        Err(Ok(RunClubError::RecorderAlreadyExists))
+    );
+    assert_eq!(client.get_recorders(&club_id).len(), 1);
+
+    // O registrador envia a corrida sem assinatura do oráculo
+    let oracle = common::Oracle::default();
+    let attestation = oracle.attest(&env, &runner, club_id, 5_000);
+    client.record_km_tokens(&backend, &attestation);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 5 * KM_UNIT);
+    assert_eq!(
+        client.try_record_km_tokens(&backend, &attestation),
+        Err(Ok(RunClubError::NonceAlreadyUsed))
+    );
+
+    // O corredor não se credita sozinho, nem pelo caminho do registrador
+    let attestation = oracle.attest(&env, &runner, club_id, 5_000);
+    assert_eq!(
+        client.try_record_km_tokens(&runner, &attestation),
+        Err(Ok(RunClubError::NotRecorder))
+    );
+
+    client.remove_recorder(&club_id, &organizer, &backend);
+    assert_eq!(
+        client.try_record_km_tokens(&backend, &attestation),
+        Err(Ok(RunClubError::NotRecorder))
+    );
+    assert_eq!(
+        client.try_remove_recorder(&club_id, &organizer, &backend),
+        Err(Ok(RunClubError::RecorderNotFound))
+    );
+}
+
+#[test]
+fn test_tc022_auth_covers_the_exact_run() {
+    let env = Env::default();
+    let (client, club_id, organizer, runner) = setup(&env);
+    let backend = Address::generate(&env);
+    client.add_recorder(&club_id, &organizer, &backend);
+
+    let oracle = common::Oracle::default();
+    let attestation = oracle.attest(&env, &runner, club_id, 5_000);
+    let args: Vec<Val> = (attestation.clone(),).into_val(&env);
+
+    // Autorização do registrador para esta atestação apenas
+    let auth = MockAuth {
+        address: &backend,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "record_km_tokens",
+            args: args.clone(),
+            sub_invokes: &[],
+        },
+    };
+
+    // Outra corrida com a mesma autorização é recusada
+    let mut forged = attestation.clone();
+    forged.distance_meters = 42_000;
+    assert!(client
+        .mock_auths(std::slice::from_ref(&auth))
+        .try_record_km_tokens(&backend, &forged)
+        .is_err());
+
+    client
+        .mock_auths(&[auth])
+        .record_km_tokens(&backend, &attestation);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            backend.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    client.address.clone(),
+                    Symbol::new(&env, "record_km_tokens"),
+                    args,
+                )),
+                sub_invocations: std::vec![],
+            }
+        )]
+    );
+
+    // No caminho do oráculo, o corredor assina só a atestação
+    let (attestation, oracle_key, signature) =
+        common::attest_km(&env, &client, club_id, &runner, 2);
+    let args: Vec<Val> = (attestation.clone(),).into_val(&env);
+    client
+        .mock_auths(&[MockAuth {
+            address: &runner,
+            invoke: &MockAuthInvoke {
+                contract: &client.address,
+                fn_name: "add_km_tokens",
+                args,
+                sub_invokes: &[],
+            },
+        }])
+        .add_km_tokens(&attestation, &oracle_key, &signature);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 7 * KM_UNIT);
+}
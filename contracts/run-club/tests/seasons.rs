@@ -0,0 +1,217 @@
+//! Teste TC-019: Temporadas Recorrentes
+//!
+//! Objetivo: Verificar que o organizador abre novas temporadas no mesmo clube, com
+//! os mesmos membros, os KM zerados e o histórico das temporadas anteriores.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • "Clube Alpha" tem 100 USDC, regra de "Distribuição Igual" e prazo de resgate de 7 dias
+//! • Usuário B correu 5 km e Usuário C correu 3 km na primeira temporada
+//!
+//! Cenário:
+//! 1. A primeira temporada termina e Usuário B resgata; Usuário C não resgata
+//! 2. Usuário A abre a segunda temporada, levando ou não o USDC que sobrou
+//! 3. Os membros correm de novo e consultam o histórico
+//!
+//! Resultados Esperados:
+//! • A nova temporada só abre depois do prazo de resgate enquanto houver valores pendentes
+//! • Os membros continuam no clube com os KM zerados
+//! • O USDC que sobrou vai para o pool da nova temporada ou volta ao organizador
+//! • Temporadas, fechamentos e valores anteriores continuam consultáveis
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token::TokenClient, Address, Env};
+
+mod common;
+
+use run_club::{ClubStatus, RunClubContractClient, RunClubError, WithdrawalRule, KM_UNIT};
+
+use common::DAY;
+
+fn setup<'a>(
+    env: &Env,
+) -> (
+    RunClubContractClient<'a>,
+    TokenClient<'a>,
+    u64,
+    [Address; 3],
+) {
+    let config = common::club_config(WithdrawalRule::Equal);
+    let (client, usdc, club_id, organizer) = common::setup_club(env, &config, 0);
+    client.set_claim_window(&club_id, &organizer, &(7 * DAY));
+    common::mint_usdc(env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+
+    let member_b = Address::generate(env);
+    let member_c = Address::generate(env);
+    for (member, km) in [(&member_b, 5i128), (&member_c, 3)] {
+        client.add_member(&club_id, member);
+        common::add_km(env, &client, club_id, member, km);
+    }
+
+    (client, usdc, club_id, [organizer, member_b, member_c])
+}
+
+/// Fim de uma temporada de 30 dias que começa agora
+fn season_end(env: &Env) -> u64 {
+    env.ledger().timestamp() + 30 * DAY
+}
+
+#[test]
+fn test_tc019_new_season_rolls_over_unclaimed_usdc() {
+    let env = Env::default();
+    let (client, usdc, club_id, [organizer, member_b, member_c]) = setup(&env);
+    let first_end = client.get_club(&club_id).month_end_timestamp;
+
+    // A temporada precisa ter terminado
+    assert_eq!(
+        client.try_start_new_season(
+            &club_id,
+            &organizer,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &true,
+        ),
+        Err(Ok(RunClubError::ClubPeriodNotEnded))
+    );
+
+    common::set_time(&env, first_end + 1);
+    assert_eq!(client.redeem_usdc(&club_id, &member_b, &member_b), 50i128);
+
+    // Usuário C ainda pode resgatar
+    assert_eq!(
+        client.try_start_new_season(
+            &club_id,
+            &organizer,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &true,
+        ),
+        Err(Ok(RunClubError::ClaimWindowOpen))
+    );
+    assert_eq!(
+        client.try_start_new_season(
+            &club_id,
+            &member_b,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &true,
+        ),
+        Err(Ok(RunClubError::NotOrganizer))
+    );
+
+    let second_start = first_end + 7 * DAY + 1;
+    common::set_time(&env, second_start);
+    assert_eq!(
+        client.start_new_season(
+            &club_id,
+            &organizer,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &true,
+        ),
+        2u32
+    );
+
+    let club = client.get_club(&club_id);
+    assert_eq!(club.season, 2);
+    assert_eq!(club.status, ClubStatus::Active);
+    assert_eq!(club.usdc_deposited, 50i128);
+    assert_eq!(club.month_end_timestamp, second_start + 30 * DAY);
+    assert_eq!(club.member_count, 2);
+    assert_eq!(usdc.balance(&organizer), 0i128);
+
+    // Os KM recomeçam e as corridas contam para a nova temporada
+    assert_eq!(client.get_user_km_tokens(&member_c, &club_id), 0i128);
+    common::set_time(&env, second_start + DAY);
+    common::add_km(&env, &client, club_id, &member_c, 2i128);
+    assert_eq!(client.get_user_km_tokens(&member_c, &club_id), 2 * KM_UNIT);
+    let runs = client.get_user_runs(&club_id, &member_c, &0u32, &10u32);
+    assert_eq!(runs.get(0).unwrap().season, 1);
+    assert_eq!(runs.get(1).unwrap().season, 2);
+    assert_eq!(
+        client.try_get_settlement(&club_id),
+        Err(Ok(RunClubError::ClubNotSettled))
+    );
+
+    // Histórico da primeira temporada
+    let seasons = client.get_seasons(&club_id, &0u32, &10u32);
+    assert_eq!(seasons.len(), 2);
+    assert_eq!(seasons.get(0).unwrap().end_timestamp, first_end);
+    assert_eq!(seasons.get(1).unwrap().rolled_over, 50i128);
+    assert_eq!(client.get_season(&club_id, &2u32), seasons.get(1).unwrap());
+
+    let first = client.get_season_settlement(&club_id, &1u32);
+    assert_eq!(first.total_payout, 100i128);
+    assert_eq!(first.claimed, 50i128);
+    assert!(client.get_season_payout(&club_id, &1u32, &member_b).claimed);
+    let payout_c = client.get_season_payout(&club_id, &1u32, &member_c);
+    assert_eq!(payout_c.km, 3 * KM_UNIT);
+    assert!(!payout_c.claimed);
+    assert_eq!(
+        client.try_get_season(&club_id, &3u32),
+        Err(Ok(RunClubError::SeasonNotFound))
+    );
+
+    // Só Usuário C correu na segunda temporada e leva o pool herdado
+    common::set_time(&env, club.month_end_timestamp + 1);
+    assert_eq!(client.redeem_usdc(&club_id, &member_c, &member_c), 50i128);
+    assert_eq!(
+        client
+            .get_season_settlement(&club_id, &2u32)
+            .eligible_members,
+        1
+    );
+}
+
+#[test]
+fn test_tc019_new_season_returns_leftover_to_organizer() {
+    let env = Env::default();
+    let (client, usdc, club_id, [organizer, member_b, member_c]) = setup(&env);
+    let first_end = client.get_club(&club_id).month_end_timestamp;
+
+    // Com tudo resgatado, a nova temporada abre antes do fim do prazo
+    common::set_time(&env, first_end + 1);
+    client.redeem_usdc(&club_id, &member_b, &member_b);
+    client.redeem_usdc(&club_id, &member_c, &member_c);
+    assert_eq!(
+        client.start_new_season(
+            &club_id,
+            &organizer,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &false,
+        ),
+        2u32
+    );
+    assert_eq!(client.get_season(&club_id, &2u32).rolled_over, 0i128);
+
+    // Ninguém correu: a temporada fecha sem pagar e o pool volta ao organizador
+    common::mint_usdc(&env, &usdc, &organizer, 40i128);
+    client.deposit_usdc(&club_id, &organizer, &40i128);
+    common::set_time(&env, client.get_club(&club_id).month_end_timestamp + 1);
+    assert_eq!(
+        client.start_new_season(
+            &club_id,
+            &organizer,
+            &env.ledger().timestamp(),
+            &season_end(&env),
+            &season_end(&env),
+            &false,
+        ),
+        3u32
+    );
+    assert_eq!(usdc.balance(&organizer), 40i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+
+    let second = client.get_season_settlement(&club_id, &2u32);
+    assert_eq!(second.total_payout, 0i128);
+    assert_eq!(second.swept, 40i128);
+    assert_eq!(client.get_seasons(&club_id, &1u32, &10u32).len(), 2);
+}
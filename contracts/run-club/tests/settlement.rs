@@ -0,0 +1,157 @@
+//! Teste TC-017: Fechamento do Período e Valores Fixados
+//!
+//! Objetivo: Verificar que o fechamento do clube fixa o valor de cada membro elegível
+//! e que a ordem dos resgates não muda o que cada um recebe.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • "Clube Alpha" tem 100 USDC e regra de "Distribuição Igual"
+//! • Usuário B correu 5 km, Usuário C correu 3 km e Usuário D não correu
+//!
+//! Cenário:
+//! 1. O período termina e qualquer um fecha o clube, de uma vez ou um membro por chamada
+//! 2. Os membros resgatam em todas as ordens possíveis
+//!
+//! Resultados Esperados:
+//! • Só B e C são elegíveis e cada um recebe 50 USDC, em qualquer ordem
+//! • Usuário D não dilui o pool e não tem o que resgatar
+//! • O clube passa para `Settled` e não pode ser fechado duas vezes
+//! • Em páginas, o fechamento só termina depois de percorrer todos os membros, e
+//!   ninguém sai do clube no meio dele
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+mod common;
+
+use run_club::{ClubStatus, RunClubContractClient, RunClubError, WithdrawalRule, KM_UNIT};
+
+fn setup<'a>(env: &Env) -> (RunClubContractClient<'a>, u64, [Address; 3]) {
+    let config = common::club_config(WithdrawalRule::Equal);
+    let (client, usdc, club_id, organizer) = common::setup_club(env, &config, 0);
+    client.activate(&club_id, &organizer);
+    common::mint_usdc(env, &usdc, &organizer, 100i128);
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+
+    let members = [
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    for member in members.iter() {
+        client.add_member(&club_id, member);
+    }
+    common::add_km(env, &client, club_id, &members[0], 5i128);
+    common::add_km(env, &client, club_id, &members[1], 3i128);
+
+    (client, club_id, members)
+}
+
+#[test]
+fn test_tc017_settlement_fixes_payouts() {
+    let env = Env::default();
+    let (client, club_id, [member_b, member_c, member_d]) = setup(&env);
+
+    // Ainda não há fechamento
+    assert_eq!(
+        client.try_settle_club(&club_id, &10u32),
+        Err(Ok(RunClubError::ClubPeriodNotEnded))
+    );
+    assert_eq!(
+        client.try_get_settlement(&club_id),
+        Err(Ok(RunClubError::ClubNotSettled))
+    );
+
+    common::end_period(&env, &client, club_id);
+    let settlement = client.settle_club(&club_id, &10u32).unwrap();
+    assert_eq!(settlement.pool, 100i128);
+    assert_eq!(settlement.total_km, 8 * KM_UNIT);
+    assert_eq!(settlement.eligible_members, 2);
+    assert_eq!(settlement.total_payout, 100i128);
+    assert_eq!(settlement.claimed, 0i128);
+    assert_eq!(client.get_settlement(&club_id), settlement);
+    assert_eq!(client.get_club(&club_id).status, ClubStatus::Settled);
+    assert_eq!(
+        client.try_settle_club(&club_id, &10u32),
+        Err(Ok(RunClubError::ClubAlreadySettled))
+    );
+
+    let payout_b = client.get_payout(&club_id, &member_b);
+    assert_eq!(payout_b.km, 5 * KM_UNIT);
+    assert_eq!(payout_b.amount, 50i128);
+    assert!(!payout_b.claimed);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 50i128);
+
+    // Quem não correu não entra no fechamento
+    assert_eq!(
+        client.try_get_payout(&club_id, &member_d),
+        Err(Ok(RunClubError::NoKmTokens))
+    );
+    assert_eq!(
+        client.try_redeem_usdc(&club_id, &member_d, &member_d),
+        Err(Ok(RunClubError::NoKmTokens))
+    );
+
+    // Um valor só pode ser resgatado uma vez
+    client.redeem_usdc(&club_id, &member_b, &member_b);
+    assert!(client.get_payout(&club_id, &member_b).claimed);
+    assert_eq!(client.calculate_usdc_reward(&club_id, &member_b), 0i128);
+    assert_eq!(client.get_settlement(&club_id).claimed, 50i128);
+    assert_eq!(
+        client.try_redeem_usdc(&club_id, &member_b, &member_b),
+        Err(Ok(RunClubError::NoKmTokens))
+    );
+}
+
+#[test]
+fn test_tc017_redemption_order_does_not_change_payouts() {
+    // Cada ordem de resgate, com o fechamento feito pelo primeiro resgate
+    for order in [[0usize, 1, 2], [1, 0, 2], [2, 1, 0], [2, 0, 1]] {
+        let env = Env::default();
+        let (client, club_id, members) = setup(&env);
+        common::end_period(&env, &client, club_id);
+
+        let mut paid = [0i128; 3];
+        for index in order {
+            let member = &members[index];
+            paid[index] = client
+                .try_redeem_usdc(&club_id, member, member)
+                .map_or(0, |result| result.unwrap());
+        }
+
+        assert_eq!(paid, [50i128, 50, 0]);
+        assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+        assert_eq!(client.get_club(&club_id).status, ClubStatus::Settled);
+    }
+}
+
+#[test]
+fn test_tc017_settlement_in_pages() {
+    let env = Env::default();
+    let (client, club_id, [member_b, member_c, member_d]) = setup(&env);
+    common::end_period(&env, &client, club_id);
+
+    // Três membros: soma dos KM e valores fixados, um por chamada
+    for _ in 0..5 {
+        assert_eq!(client.settle_club(&club_id, &1u32), None);
+        assert_eq!(
+            client.try_get_settlement(&club_id),
+            Err(Ok(RunClubError::ClubNotSettled))
+        );
+    }
+    assert_eq!(
+        client.try_leave_club(&club_id, &member_d),
+        Err(Ok(RunClubError::ClubNotSettled))
+    );
+
+    let settlement = client.settle_club(&club_id, &1u32).unwrap();
+    assert_eq!(settlement.eligible_members, 2);
+    assert_eq!(settlement.total_payout, 100i128);
+    assert_eq!(client.get_club(&club_id).status, ClubStatus::Settled);
+    assert_eq!(client.get_payout(&club_id, &member_b).amount, 50i128);
+    assert_eq!(client.get_payout(&club_id, &member_c).amount, 50i128);
+
+    client.leave_club(&club_id, &member_d);
+    assert_eq!(client.get_club(&club_id).member_count, 2);
+}
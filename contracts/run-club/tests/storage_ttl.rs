@@ -0,0 +1,285 @@
+//! Teste TC-011: TTL do Armazenamento
+//!
+//! Objetivo: Verificar que as entradas de clubes, membros e saldos são estendidas ao
+//! serem usadas e que `bump_club` as mantém vivas até o fim da janela de resgate.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A criou o "Clube Alpha", depositou USDC e Usuário B entrou no clube
+//!
+//! Cenário:
+//! 1. Usuário B registra uma corrida
+//! 2. Alguns dias se passam
+//! 3. Um keeper chama `bump_club` página a página e `bump_member` para quem saiu
+//! 4. O clube passa para a segunda temporada
+//!
+//! Resultados Esperados:
+//! • Toda escrita deixa a entrada com pelo menos o TTL padrão
+//! • `bump_club` estende as entradas do clube até `month_end_timestamp` mais a janela de resgate
+//! • Cada página só estende os membros das suas posições, no máximo `MAX_BUMP_PAGE`;
+//!   temporadas passadas também contam
+//! • `bump_member` estende as corridas e os KM congelados de um membro removido
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+mod common;
+
+use run_club::{
+    ClubConfig, DataKey, RemovedKmPolicy, RunClubContractClient, RunLimits, SurplusRule,
+    WithdrawalRule, KM_UNIT, LEDGER_CLOSE_SECONDS, MAX_BUMP_PAGE, PERSISTENT_BUMP_AMOUNT,
+    REDEMPTION_GRACE_PERIOD,
+};
+
+fn ttl(env: &Env, contract_id: &Address, key: &DataKey) -> u32 {
+    env.as_contract(contract_id, || env.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_tc011_entries_are_bumped_when_touched() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &runner);
+    common::add_km(&env, &client, club_id, &runner, 5i128);
+
+    let keys = [
+        DataKey::Club(club_id),
+        DataKey::Member(club_id, runner.clone()),
+        DataKey::MemberAt(club_id, 0),
+        DataKey::UserKmTokens(runner.clone(), club_id, 1),
+        DataKey::KmTotals(club_id, 1),
+        DataKey::UserClubs(runner.clone()),
+        DataKey::UserClubs(organizer.clone()),
+    ];
+    for key in keys.iter() {
+        assert!(ttl(&env, &contract_id, key) >= PERSISTENT_BUMP_AMOUNT);
+    }
+
+    // Vinte dias depois o TTL caiu; uma nova corrida volta a estendê-lo
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 20 * 17280;
+        li.timestamp += 20 * 24 * 60 * 60;
+    });
+    let km_key = DataKey::UserKmTokens(runner.clone(), club_id, 1);
+    assert!(ttl(&env, &contract_id, &km_key) < PERSISTENT_BUMP_AMOUNT);
+
+    common::add_km(&env, &client, club_id, &runner, 5i128);
+    assert!(ttl(&env, &contract_id, &km_key) >= PERSISTENT_BUMP_AMOUNT);
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 10 * KM_UNIT);
+}
+
+#[test]
+fn test_tc011_bump_club_until_redemption_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+
+    // Clube de 90 dias: mais longo que o TTL padrão
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(90),
+            end_timestamp: common::days(90),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.add_member(&club_id, &runner);
+    common::add_km(&env, &client, club_id, &runner, 5i128);
+
+    let club = client.get_club(&club_id);
+    let seconds = club.month_end_timestamp + REDEMPTION_GRACE_PERIOD - env.ledger().timestamp();
+    let expected = seconds.div_ceil(LEDGER_CLOSE_SECONDS) as u32;
+    assert!(expected > PERSISTENT_BUMP_AMOUNT);
+
+    // Qualquer um pode chamar
+    assert_eq!(client.bump_club(&club_id, &0u32, &10u32), expected);
+
+    let keys = [
+        DataKey::Club(club_id),
+        DataKey::Member(club_id, runner.clone()),
+        DataKey::MemberAt(club_id, 0),
+        DataKey::UserKmTokens(runner.clone(), club_id, 1),
+        DataKey::KmTotals(club_id, 1),
+        DataKey::UserClubs(runner.clone()),
+        DataKey::UserClubs(organizer.clone()),
+        DataKey::ClubStatusIndex(club_id),
+    ];
+    for key in keys.iter() {
+        assert!(ttl(&env, &contract_id, key) >= expected);
+    }
+
+    // Sem o bump, as entradas já teriam expirado antes do fim do clube
+    env.ledger().with_mut(|li| {
+        li.sequence_number += PERSISTENT_BUMP_AMOUNT + 1;
+        li.timestamp += (PERSISTENT_BUMP_AMOUNT as u64 + 1) * LEDGER_CLOSE_SECONDS;
+    });
+    assert_eq!(client.get_user_km_tokens(&runner, &club_id), 5 * KM_UNIT);
+    assert_eq!(client.get_club(&club_id).member_count, 1);
+}
+
+#[test]
+fn test_tc011_bump_club_pages_and_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    common::mint_usdc(&env, &usdc, &organizer, 100i128);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(90),
+            end_timestamp: common::days(90),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    client.deposit_usdc(&club_id, &organizer, &100i128);
+    client.set_removed_km_policy(&club_id, &organizer, &RemovedKmPolicy::Freeze);
+
+    let [member_b, member_c, member_d] = core::array::from_fn(|_| Address::generate(&env));
+    for member in [&member_b, &member_c, &member_d] {
+        client.add_member(&club_id, member);
+        common::add_km(&env, &client, club_id, member, 5i128);
+    }
+    let run = client
+        .get_user_runs(&club_id, &member_c, &0u32, &10u32)
+        .get(0)
+        .unwrap();
+
+    // C sai com os KM congelados; D passa para a posição 1
+    client.remove_member(&club_id, &organizer, &member_c);
+    let code_hash = BytesN::from_array(&env, &[5; 32]);
+    client.add_invite(&club_id, &organizer, &code_hash);
+
+    let expected = client.bump_club(&club_id, &0u32, &1u32);
+    assert!(expected > PERSISTENT_BUMP_AMOUNT);
+    assert!(ttl(&env, &contract_id, &DataKey::Invite(club_id, code_hash)) >= expected);
+    assert!(
+        ttl(
+            &env,
+            &contract_id,
+            &DataKey::Member(club_id, member_b.clone())
+        ) >= expected
+    );
+    let member_d_key = DataKey::Member(club_id, member_d.clone());
+    assert!(ttl(&env, &contract_id, &member_d_key) < expected);
+    client.bump_club(&club_id, &1u32, &1u32);
+    assert!(ttl(&env, &contract_id, &member_d_key) >= expected);
+
+    // Uma página maior que `MAX_BUMP_PAGE` para no limite
+    let extra: std::vec::Vec<Address> = (0..MAX_BUMP_PAGE)
+        .map(|_| Address::generate(&env))
+        .collect();
+    for member in &extra {
+        client.add_member(&club_id, member);
+    }
+    client.bump_club(&club_id, &0u32, &100u32);
+    let last_key = DataKey::Member(club_id, extra[extra.len() - 1].clone());
+    assert!(
+        ttl(
+            &env,
+            &contract_id,
+            &DataKey::Member(club_id, extra[0].clone())
+        ) >= expected
+    );
+    assert!(ttl(&env, &contract_id, &last_key) < expected);
+
+    // O histórico e os KM congelados de C
+    let keys = [
+        DataKey::UserKmTokens(member_c.clone(), club_id, 1),
+        DataKey::RunCount(club_id, member_c.clone()),
+        DataKey::Run(club_id, member_c.clone(), 0),
+        DataKey::RunId(club_id, run.run_id.clone()),
+        DataKey::DailyMeters(club_id, member_c.clone(), run.end_timestamp / common::DAY),
+    ];
+    for key in keys.iter() {
+        assert!(ttl(&env, &contract_id, key) < expected);
+    }
+    assert_eq!(
+        client.bump_member(&club_id, &member_c, &0u32, &10u32),
+        expected
+    );
+    for key in keys.iter() {
+        assert!(ttl(&env, &contract_id, key) >= expected);
+    }
+
+    // Na segunda temporada, o clube segue estendendo a temporada e o fechamento da
+    // primeira, e os saldos dela ficam com `bump_member`
+    let first_end = client.get_club(&club_id).month_end_timestamp;
+    env.ledger().with_mut(|li| {
+        li.timestamp = first_end + 1;
+    });
+    let settlement = client.settle_club(&club_id, &100u32).unwrap();
+    env.ledger().with_mut(|li| {
+        li.timestamp = settlement.claim_deadline + 1;
+    });
+    let second_end = settlement.claim_deadline + common::days(90);
+    client.start_new_season(
+        &club_id,
+        &organizer,
+        &(settlement.claim_deadline + 1),
+        &second_end,
+        &second_end,
+        &false,
+    );
+    let expected = client.bump_club(&club_id, &0u32, &10u32);
+    for key in [DataKey::Season(club_id, 1), DataKey::Settlement(club_id, 1)].iter() {
+        assert!(ttl(&env, &contract_id, key) >= expected);
+    }
+    let keys = [
+        DataKey::Payout(club_id, 1, member_b.clone()),
+        DataKey::UserKmTokens(member_b.clone(), club_id, 1),
+    ];
+    client.bump_member(&club_id, &member_b, &0u32, &10u32);
+    for key in keys.iter() {
+        assert!(ttl(&env, &contract_id, key) >= expected);
+    }
+}
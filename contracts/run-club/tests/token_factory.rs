@@ -0,0 +1,120 @@
+//! Teste TC-006: Fábrica de Tokens KM
+//!
+//! Objetivo: Verificar que o token KM de cada clube é implantado em um endereço
+//! determinístico e que apenas o admin pode trocar o wasm usado pela fábrica.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado com um admin, o USDC e o wasm do token KM
+//!
+//! Cenário:
+//! 1. Consultar o endereço previsto do token dos clubes 1 e 2
+//! 2. Criar os dois clubes
+//! 3. Tentar registrar um novo wasm sem e com a assinatura do admin
+//!
+//! Resultados Esperados:
+//! • O token de cada clube é implantado exatamente no endereço previsto
+//! • Clubes diferentes recebem tokens diferentes, administrados pelo contrato
+//! • O registro do wasm exige a assinatura do admin
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction},
+    Address, Env, IntoVal, String, Symbol,
+};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RunClubContract, RunClubContractClient, RunLimits, SurplusRule, WithdrawalRule,
+};
+
+#[test]
+fn test_tc006_token_address_is_predictable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+
+    // Endereços previstos antes de qualquer clube existir
+    let predicted_1 = client.get_club_token_address(&1u64);
+    let predicted_2 = client.get_club_token_address(&2u64);
+    assert_ne!(predicted_1, predicted_2);
+
+    let club_1 = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    let club_2 = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Beta"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+
+    assert_eq!(client.get_club(&club_1).token_address, predicted_1);
+    assert_eq!(client.get_club(&club_2).token_address, predicted_2);
+
+    // O contrato é o administrador do token de cada clube
+    let km_token = common::KmTokenClient::new(&env, &predicted_1);
+    assert_eq!(km_token.admin(), contract_id);
+}
+
+#[test]
+fn test_tc006_set_token_wasm_hash_requires_admin() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let usdc = common::create_usdc(&env);
+    let wasm_hash = common::upload_km_token(&env);
+    let contract_id = env.register(
+        RunClubContract,
+        (
+            admin.clone(),
+            usdc.address,
+            wasm_hash.clone(),
+            common::PLATFORM_FEE_BPS,
+        ),
+    );
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    // Sem assinatura do admin
+    assert!(client.try_set_token_wasm_hash(&wasm_hash).is_err());
+
+    // Com a assinatura do admin
+    env.mock_all_auths();
+    client.set_token_wasm_hash(&wasm_hash);
+
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, admin);
+    assert_eq!(
+        auths[0].1.function,
+        AuthorizedFunction::Contract((
+            contract_id.clone(),
+            Symbol::new(&env, "set_token_wasm_hash"),
+            (wasm_hash.clone(),).into_val(&env),
+        ))
+    );
+}
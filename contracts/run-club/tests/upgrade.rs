@@ -0,0 +1,274 @@
+//! Teste TC-008: Atualização do Contrato e Migração do Schema
+//!
+//! Objetivo: Verificar que apenas o admin atualiza o contrato e que os clubes
+//! salvos no layout antigo continuam acessíveis depois da migração.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado e possui um clube salvo no layout da versão 1
+//!
+//! Cenário:
+//! 1. Um usuário qualquer tenta atualizar o wasm do contrato
+//! 2. O admin executa a migração da versão 1 até a versão atual, em páginas
+//!
+//! Resultados Esperados:
+//! • A atualização exige a assinatura do admin
+//! • O clube antigo é reescrito no novo layout sem perder dados
+//! • Os membros do clube antigo passam a ter registros próprios
+//! • O endereço de token nunca implantado dá lugar ao token KM do clube
+//! • Os KM do clube antigo passam para a primeira temporada, em unidades do token
+//!   e com os tokens KM emitidos, e continuam resgatáveis
+//! • Cada página migra no máximo `limit` clubes e membros; a versão só passa a ser a
+//!   atual na última página, e a migração pode ser repetida sem efeito
+//! • Uma versão de schema desconhecida é recusada com `UnsupportedSchemaVersion`
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    contracttype,
+    testutils::{Address as _, Ledger},
+    token, vec, Address, Env, String, Vec,
+};
+
+mod common;
+
+use run_club::{
+    ClubStatus, DataKey, MemberRole, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule, KM_UNIT, SCHEMA_VERSION,
+};
+
+/// Layout do `Club` na versão 1 do schema, com os membros dentro do próprio clube
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ClubV1 {
+    id: u64,
+    name: String,
+    organizer: Address,
+    members: Vec<Address>,
+    usdc_deposited: i128,
+    usdc_per_km: i128,
+    withdrawal_rule: WithdrawalRule,
+    month_end_timestamp: u64,
+    is_active: bool,
+    token_address: Address,
+}
+
+/// Chaves da versão 1 que mudaram de formato
+#[contracttype]
+#[derive(Clone)]
+enum LegacyDataKey {
+    UserKmTokens(Address, u64),
+}
+
+/// Clube da versão 1 com os membros e os seus KM inteiros
+fn seed_club(env: &Env, contract_id: &Address, club: &ClubV1, km: &[i128]) {
+    env.as_contract(contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Club(club.id), club);
+        for (member, km) in club.members.iter().zip(km) {
+            storage.set(&LegacyDataKey::UserKmTokens(member, club.id), km);
+        }
+    });
+}
+
+/// Marca o schema na versão `version` com `club_counter` clubes criados
+fn seed_schema(env: &Env, contract_id: &Address, version: u32, club_counter: u64) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClubCounter, &club_counter);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &version);
+    });
+}
+
+#[test]
+fn test_tc008_upgrade_requires_admin() {
+    let env = Env::default();
+
+    env.mock_all_auths();
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+    env.set_auths(&[]);
+
+    let new_wasm_hash = common::upload_km_token(&env);
+    assert!(client.try_upgrade(&new_wasm_hash).is_err());
+    assert!(client.try_migrate(&10u32).is_err());
+}
+
+#[test]
+fn test_tc008_migrate_club_from_v1_to_v2() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, usdc) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+    let token_address = Address::generate(&env);
+    let mut members = Vec::new(&env);
+    members.push_back(runner.clone());
+
+    // Simular o estado deixado pela versão 1 do contrato
+    let old_club = ClubV1 {
+        id: 1,
+        name: String::from_str(&env, "Clube Legado"),
+        organizer: organizer.clone(),
+        members: members.clone(),
+        usdc_deposited: 100,
+        usdc_per_km: 1,
+        withdrawal_rule: WithdrawalRule::Unlimited,
+        month_end_timestamp: 2_592_000,
+        is_active: true,
+        token_address: token_address.clone(),
+    };
+    seed_club(&env, &contract_id, &old_club, &[5]);
+    seed_schema(&env, &contract_id, 1, 1);
+    assert_eq!(client.get_schema_version(), 1u32);
+
+    // Migrar para a versão atual
+    assert_eq!(client.migrate(&10u32), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    let club = client.get_club(&1u64);
+    assert_eq!(club.id, 1);
+    assert_eq!(club.name, String::from_str(&env, "Clube Legado"));
+    assert_eq!(club.organizer, organizer);
+    assert_eq!(club.member_count, 1);
+    assert_eq!(client.get_members(&1u64, &0u32, &10u32), members);
+    assert_eq!(client.get_member(&1u64, &runner).role, MemberRole::Member);
+    let runner_clubs = client.get_user_clubs(&runner, &0u32, &10u32);
+    assert_eq!(runner_clubs.len(), 1);
+    assert!(runner_clubs.get(0).unwrap().is_member);
+    assert!(
+        client
+            .get_user_clubs(&organizer, &0u32, &10u32)
+            .get(0)
+            .unwrap()
+            .is_organizer
+    );
+    assert_eq!(club.usdc_deposited, 100);
+    assert_eq!(club.usdc_per_km, 1);
+    assert_eq!(club.withdrawal_rule, WithdrawalRule::Unlimited);
+    assert_eq!(club.month_end_timestamp, 2_592_000);
+    assert!(club.is_active);
+    assert_ne!(club.token_address, token_address);
+    assert_eq!(club.token_address, client.get_club_token_address(&1u64));
+    assert_eq!(club.created_at, 0);
+    assert_eq!(club.status, ClubStatus::Active);
+    assert_eq!(club.limits, RunLimits::default());
+    assert_eq!(club.surplus_rule, SurplusRule::ProRata);
+    assert_eq!(club.season, 1);
+    assert_eq!(client.get_season(&1u64, &1u32).end_timestamp, 2_592_000);
+    assert_eq!(client.get_user_km_tokens(&runner, &1u64), 5 * KM_UNIT);
+    let km_token = token::Client::new(&env, &club.token_address);
+    assert_eq!(km_token.balance(&runner), 5 * KM_UNIT);
+    assert_eq!(client.get_active_clubs(&0u32, &10u32).len(), 1);
+
+    // Rodar de novo não altera nada
+    assert_eq!(client.migrate(&10u32), SCHEMA_VERSION);
+    assert_eq!(client.get_club(&1u64), club);
+
+    // O clube migrado volta a ser salvo normalmente
+    common::mint_usdc(&env, &usdc, &organizer, 50i128);
+    client.deposit_usdc(&1u64, &organizer, &50i128);
+    assert_eq!(client.get_club(&1u64).usdc_deposited, 150);
+
+    // O corredor resgata o pool queimando os tokens emitidos na migração
+    common::mint_usdc(&env, &usdc, &contract_id, 100i128);
+    env.ledger().with_mut(|li| {
+        li.timestamp = 2_592_000 + 1;
+    });
+    assert_eq!(client.redeem_usdc(&1u64, &runner, &runner), 150i128);
+    assert_eq!(km_token.balance(&runner), 0);
+    assert_eq!(usdc.balance(&runner), 150i128);
+}
+
+#[test]
+fn test_tc008_migrate_v1_in_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runners = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let members = vec![
+        &env,
+        runners[0].clone(),
+        organizer.clone(),
+        runners[1].clone(),
+        runners[2].clone(),
+    ];
+    let club = |id: u64, members: Vec<Address>| ClubV1 {
+        id,
+        name: String::from_str(&env, "Clube Legado"),
+        organizer: organizer.clone(),
+        members,
+        usdc_deposited: 0,
+        usdc_per_km: 1,
+        withdrawal_rule: WithdrawalRule::Equal,
+        month_end_timestamp: 2_592_000,
+        is_active: false,
+        token_address: Address::generate(&env),
+    };
+
+    // O clube 2 foi removido na versão 1 e o clube 3 não tem membros
+    seed_club(&env, &contract_id, &club(1, members.clone()), &[1, 2, 3, 4]);
+    seed_club(&env, &contract_id, &club(3, Vec::new(&env)), &[]);
+    seed_schema(&env, &contract_id, 1, 3);
+
+    // Cada página migra no máximo 3 clubes e membros, e o schema só muda no fim
+    assert_eq!(client.migrate(&3u32), 1u32);
+    assert_eq!(client.migrate(&3u32), 1u32);
+    assert_eq!(client.get_schema_version(), 1u32);
+    assert_eq!(client.migrate(&3u32), SCHEMA_VERSION);
+    assert_eq!(client.get_schema_version(), SCHEMA_VERSION);
+
+    let migrated = client.get_club(&1u64);
+    assert_eq!(migrated.member_count, 4);
+    assert_eq!(client.get_members(&1u64, &0u32, &10u32), members);
+    assert_eq!(
+        client.get_member(&1u64, &organizer).role,
+        MemberRole::Organizer
+    );
+    assert_eq!(client.get_member(&1u64, &runners[2]).index, 3);
+    let km_token = token::Client::new(&env, &migrated.token_address);
+    for (member, km) in members.iter().zip([1i128, 2, 3, 4]) {
+        assert_eq!(client.get_user_km_tokens(&member, &1u64), km * KM_UNIT);
+        assert_eq!(km_token.balance(&member), km * KM_UNIT);
+    }
+
+    assert_eq!(client.get_club(&3u64).member_count, 0);
+    assert_eq!(
+        client.get_club(&3u64).token_address,
+        client.get_club_token_address(&3u64)
+    );
+    assert_eq!(
+        client.try_get_club(&2u64),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+    assert_eq!(client.get_user_clubs(&organizer, &0u32, &10u32).len(), 2);
+}
+
+#[test]
+fn test_tc008_migrate_rejects_unknown_schema() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+    seed_schema(&env, &contract_id, SCHEMA_VERSION + 1, 0);
+
+    assert_eq!(
+        client.try_migrate(&10u32),
+        Err(Ok(RunClubError::UnsupportedSchemaVersion))
+    );
+}
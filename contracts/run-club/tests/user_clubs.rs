@@ -0,0 +1,161 @@
+//! Teste TC-009: Clubes de um Usuário
+//!
+//! Objetivo: Verificar que o índice de clubes de cada usuário acompanha a criação,
+//! a entrada, a saída e a remoção de clubes.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//!
+//! Cenário:
+//! 1. Usuário A cria o "Clube Alpha" e o "Clube Beta" e corre no "Clube Alpha"
+//! 2. Usuário B entra nos dois clubes
+//! 3. Usuário B é removido do "Clube Alpha"
+//! 4. Usuário A remove o "Clube Beta", apagando os membros em páginas
+//!
+//! Resultados Esperados:
+//! • Cada usuário vê seus clubes com os papéis de membro e organizador
+//! • A consulta é paginada
+//! • Saídas e remoções atualizam o índice
+//! • Um clube em remoção some da consulta mesmo para quem ainda não foi apagado
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RunClubContractClient, RunClubError, RunLimits, SurplusRule, WithdrawalRule,
+};
+
+#[test]
+fn test_tc009_user_clubs_index() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let runner = Address::generate(&env);
+
+    let alpha = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    let beta = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Beta"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Unlimited,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+
+    client.add_member(&alpha, &organizer);
+    client.add_member(&alpha, &runner);
+    client.add_member(&beta, &runner);
+
+    // Organizador: membro e organizador do Alpha, só organizador do Beta
+    let clubs = client.get_user_clubs(&organizer, &0u32, &10u32);
+    assert_eq!(clubs.len(), 2);
+    let first = clubs.get(0).unwrap();
+    assert_eq!(first.club.id, alpha);
+    assert_eq!(first.club.name, String::from_str(&env, "Clube Alpha"));
+    assert_eq!(first.club.member_count, 2);
+    assert!(first.is_member && first.is_organizer);
+    let second = clubs.get(1).unwrap();
+    assert_eq!(second.club.id, beta);
+    assert!(!second.is_member && second.is_organizer);
+
+    // Corredor: membro dos dois, uma página por vez
+    let page = client.get_user_clubs(&runner, &0u32, &1u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().club.id, alpha);
+    let page = client.get_user_clubs(&runner, &1u32, &1u32);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().club.id, beta);
+    assert!(!page.get(0).unwrap().is_organizer);
+    assert_eq!(client.get_user_clubs(&runner, &2u32, &1u32).len(), 0);
+
+    // Removido do Alpha, o corredor só vê o Beta
+    client.remove_member(&alpha, &organizer, &runner);
+    let clubs = client.get_user_clubs(&runner, &0u32, &10u32);
+    assert_eq!(clubs.len(), 1);
+    assert_eq!(clubs.get(0).unwrap().club.id, beta);
+
+    // Removido o Beta, ele some para o organizador e para os membros
+    client.remove_club(&beta, &organizer, &10u32);
+    assert_eq!(client.get_user_clubs(&runner, &0u32, &10u32).len(), 0);
+    let clubs = client.get_user_clubs(&organizer, &0u32, &10u32);
+    assert_eq!(clubs.len(), 1);
+    assert_eq!(clubs.get(0).unwrap().club.id, alpha);
+
+    // Um usuário sem clubes recebe uma lista vazia
+    let stranger = Address::generate(&env);
+    assert_eq!(client.get_user_clubs(&stranger, &0u32, &10u32).len(), 0);
+}
+
+#[test]
+fn test_tc009_removed_club_members_are_deleted_in_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Gama"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: None,
+        },
+    );
+    let runners = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    for runner in runners.iter() {
+        client.add_member(&club_id, runner);
+    }
+
+    // A primeira chamada tira o clube do registro e apaga os dois últimos membros
+    assert_eq!(client.remove_club(&club_id, &organizer, &2u32), 1u32);
+    assert_eq!(
+        client.try_get_club(&club_id),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+    assert_eq!(client.get_user_clubs(&runners[2], &0u32, &10u32).len(), 0);
+    assert_eq!(client.get_user_clubs(&runners[0], &0u32, &10u32).len(), 0);
+
+    assert_eq!(client.remove_club(&club_id, &organizer, &2u32), 0u32);
+    assert_eq!(
+        client.try_remove_club(&club_id, &organizer, &2u32),
+        Err(Ok(RunClubError::ClubNotFound))
+    );
+}
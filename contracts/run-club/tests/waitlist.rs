@@ -0,0 +1,233 @@
+//! Teste TC-025: Vagas Limitadas e Fila de Espera
+//!
+//! Objetivo: Verificar que um clube com `max_members` manda para a fila de espera
+//! quem chega com o clube cheio e passa cada vaga liberada ao primeiro da fila.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A cria o "Clube Alpha" com 2 vagas
+//!
+//! Cenário:
+//! 1. Usuários B e C entram; Usuários D e E chegam com o clube cheio
+//! 2. Usuário A remove B e, depois do fim das inscrições, C sai do clube
+//! 3. Usuários F, G e H entram na fila; G e F desistem
+//! 4. Uma fila longa perde todo o meio e depois o começo e o fim
+//!
+//! Resultados Esperados:
+//! • D e E esperam na ordem em que chegaram
+//! • Cada vaga liberada vai para o primeiro da fila, mesmo depois do fim das inscrições
+//! • Quem desiste sai da fila, do meio ou do começo, sem mudar a ordem dos outros
+//! • A vaga vai direto para o próximo da fila, por maior que seja o buraco deixado
+//! • Um clube sem vagas não pode ser criado
+
+#![cfg(test)]
+
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String,
+};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RunClubContractClient, RunClubError, RunLimits, SurplusRule, WithdrawalRule,
+};
+
+#[test]
+fn test_tc025_waitlist_promotes_in_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    assert_eq!(
+        client.try_create_club(
+            &organizer,
+            &String::from_str(&env, "Clube Alpha"),
+            &ClubConfig {
+                usdc_per_km: 1i128,
+                withdrawal_rule: WithdrawalRule::Equal,
+                surplus_rule: SurplusRule::ProRata,
+                start_timestamp: 0u64,
+                registration_close_timestamp: common::days(10),
+                end_timestamp: common::days(30),
+                limits: RunLimits::default(),
+                max_members: Some(0u32),
+            },
+        ),
+        Err(Ok(RunClubError::InvalidCapacity))
+    );
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(10),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: Some(2u32),
+        },
+    );
+
+    let [member_b, member_c, member_d, member_e] =
+        core::array::from_fn(|_| Address::generate(&env));
+    for member in [&member_b, &member_c, &member_d, &member_e] {
+        client.add_member(&club_id, member);
+    }
+    assert_eq!(client.get_club(&club_id).member_count, 2);
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &10u32),
+        vec![&env, member_d.clone(), member_e.clone()]
+    );
+    assert_eq!(
+        client.try_add_member(&club_id, &member_d),
+        Err(Ok(RunClubError::MemberAlreadyExists))
+    );
+
+    // A vaga de B vai para D
+    client.remove_member(&club_id, &organizer, &member_b);
+    assert_eq!(
+        client.get_members(&club_id, &0u32, &10u32),
+        vec![&env, member_c.clone(), member_d.clone()]
+    );
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &10u32),
+        vec![&env, member_e.clone()]
+    );
+
+    // Depois do fim das inscrições, a vaga de C ainda vai para E
+    env.ledger().with_mut(|li| {
+        li.timestamp = common::days(10) + 1;
+    });
+    client.leave_club(&club_id, &member_c);
+    assert_eq!(
+        client.get_members(&club_id, &0u32, &10u32),
+        vec![&env, member_d.clone(), member_e.clone()]
+    );
+    assert!(client.get_waitlist(&club_id, &0u32, &10u32).is_empty());
+    assert_eq!(client.get_club(&club_id).member_count, 2);
+
+    assert_eq!(
+        client.try_leave_club(&club_id, &member_c),
+        Err(Ok(RunClubError::NotMember))
+    );
+}
+
+#[test]
+fn test_tc025_leave_the_waitlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: Some(1u32),
+        },
+    );
+
+    let member_b = Address::generate(&env);
+    let member_f = Address::generate(&env);
+    let member_g = Address::generate(&env);
+    let member_h = Address::generate(&env);
+    client.add_member(&club_id, &member_b);
+    for member in [&member_f, &member_g, &member_h] {
+        client.add_member(&club_id, member);
+    }
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &10u32),
+        vec![&env, member_f.clone(), member_g.clone(), member_h.clone()]
+    );
+
+    // G desiste do meio da fila e F, do começo, antes de ganharem a vaga
+    client.leave_club(&club_id, &member_g);
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &10u32),
+        vec![&env, member_f.clone(), member_h.clone()]
+    );
+    client.leave_club(&club_id, &member_f);
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &1u32),
+        vec![&env, member_h.clone()]
+    );
+
+    client.leave_club(&club_id, &member_b);
+    assert!(client.get_waitlist(&club_id, &0u32, &10u32).is_empty());
+    assert_eq!(
+        client.get_members(&club_id, &0u32, &10u32),
+        vec![&env, member_h.clone()]
+    );
+    assert_eq!(
+        client.try_get_member(&club_id, &member_f),
+        Err(Ok(RunClubError::MemberNotFound))
+    );
+}
+
+#[test]
+fn test_tc025_waitlist_skips_a_long_gap() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let club_id = client.create_club(
+        &organizer,
+        &String::from_str(&env, "Clube Alpha"),
+        &ClubConfig {
+            usdc_per_km: 1i128,
+            withdrawal_rule: WithdrawalRule::Equal,
+            surplus_rule: SurplusRule::ProRata,
+            start_timestamp: 0u64,
+            registration_close_timestamp: common::days(30),
+            end_timestamp: common::days(30),
+            limits: RunLimits::default(),
+            max_members: Some(1u32),
+        },
+    );
+
+    let member_b = Address::generate(&env);
+    client.add_member(&club_id, &member_b);
+    let waiting: std::vec::Vec<Address> = (0..150).map(|_| Address::generate(&env)).collect();
+    for member in &waiting {
+        client.add_member(&club_id, member);
+    }
+
+    // Todo o meio da fila desiste, depois o último e por fim o primeiro
+    for member in &waiting[1..149] {
+        client.leave_club(&club_id, member);
+    }
+    client.leave_club(&club_id, &waiting[149]);
+    let member_i = Address::generate(&env);
+    client.add_member(&club_id, &member_i);
+    assert_eq!(
+        client.get_waitlist(&club_id, &0u32, &1u32),
+        vec![&env, waiting[0].clone()]
+    );
+    client.leave_club(&club_id, &waiting[0]);
+
+    // A vaga de B pula as posições vazias e vai para I
+    client.leave_club(&club_id, &member_b);
+    assert_eq!(
+        client.get_members(&club_id, &0u32, &10u32),
+        vec![&env, member_i.clone()]
+    );
+    assert!(client.get_waitlist(&club_id, &0u32, &100u32).is_empty());
+}
@@ -0,0 +1,179 @@
+//! Teste TC-016: Regras de Resgate por Faixas, Pódio e Distância Mínima
+//!
+//! Objetivo: Verificar a distribuição do pool nas regras `Tiered`, `TopN` e
+//! `Threshold`, independentemente da ordem dos resgates.
+//!
+//! Pré-condições:
+//! • O contrato foi inicializado
+//! • Usuário A criou um clube ativo com USDC depositado e a regra em teste
+//! • Os membros correram distâncias diferentes durante o período
+//!
+//! Cenário:
+//! 1. O período do clube termina
+//! 2. Os membros consultam suas recompensas e resgatam em ordens diferentes
+//!
+//! Resultados Esperados:
+//! • `Tiered` paga a faixa de cada um, reduzida na proporção quando o pool não cobre
+//! • `TopN` paga o pódio e divide as colocações empatadas
+//! • `Threshold` divide o pool só entre quem alcançou a distância mínima
+//! • Parâmetros incoerentes são rejeitados na criação do clube
+
+#![cfg(test)]
+
+use soroban_sdk::{testutils::Address as _, token::TokenClient, vec, Address, Env, String};
+
+mod common;
+
+use run_club::{
+    ClubConfig, RewardTier, RunClubContractClient, RunClubError, RunLimits, SurplusRule,
+    WithdrawalRule, KM_UNIT,
+};
+
+/// Cria um clube com `pool` USDC e um membro para cada distância (em km), e
+/// encerra o período
+fn setup<'a>(
+    env: &Env,
+    rule: WithdrawalRule,
+    pool: i128,
+    distances: &[i128],
+) -> (
+    RunClubContractClient<'a>,
+    TokenClient<'a>,
+    u64,
+    std::vec::Vec<Address>,
+) {
+    let (client, usdc, club_id, organizer) = common::setup_club(env, &common::club_config(rule), 0);
+    client.activate(&club_id, &organizer);
+    common::mint_usdc(env, &usdc, &organizer, pool);
+    client.deposit_usdc(&club_id, &organizer, &pool);
+
+    let mut members = std::vec::Vec::new();
+    for km in distances {
+        let member = Address::generate(env);
+        client.add_member(&club_id, &member);
+        common::add_km(env, &client, club_id, &member, *km);
+        members.push(member);
+    }
+
+    common::end_period(env, &client, club_id);
+
+    (client, usdc, club_id, members)
+}
+
+#[test]
+fn test_tc016_tiered_rewards_are_scaled_to_the_pool() {
+    let env = Env::default();
+    let tiers = vec![
+        &env,
+        RewardTier {
+            min_km: 5 * KM_UNIT,
+            reward: 30,
+        },
+        RewardTier {
+            min_km: 10 * KM_UNIT,
+            reward: 50,
+        },
+    ];
+    let (client, usdc, club_id, members) =
+        setup(&env, WithdrawalRule::Tiered(tiers), 60, &[12, 6, 2]);
+    let (b, c, d) = (&members[0], &members[1], &members[2]);
+
+    // As faixas somam 80 USDC, mas o pool só tem 60: cada uma vale 75%
+    // (37,5 e 22,5; a prévia não inclui a poeira, que vai para B no fechamento)
+    assert_eq!(client.calculate_usdc_reward(&club_id, b), 37i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, c), 22i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, d), 0i128);
+
+    // O resgate de C não muda o valor de B
+    let destination = Address::generate(&env);
+    assert_eq!(client.redeem_usdc(&club_id, c, &destination), 22i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, b), 38i128);
+    assert_eq!(client.redeem_usdc(&club_id, b, &destination), 38i128);
+    assert_eq!(usdc.balance(&destination), 60i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+}
+
+#[test]
+fn test_tc016_top_n_splits_the_podium() {
+    let env = Env::default();
+    let places = vec![&env, 5_000u32, 3_000, 2_000];
+    let (client, _usdc, club_id, members) =
+        setup(&env, WithdrawalRule::TopN(places), 100, &[10, 10, 5, 1]);
+    let (b, c, d, e) = (&members[0], &members[1], &members[2], &members[3]);
+
+    // B e C empatam em 1º e dividem 1º + 2º lugares; D é o 3º; E fica fora do pódio
+    assert_eq!(client.calculate_usdc_reward(&club_id, b), 40i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, c), 40i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, d), 20i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, e), 0i128);
+
+    // Depois que B resgata, as colocações não mudam
+    let destination = Address::generate(&env);
+    assert_eq!(client.redeem_usdc(&club_id, b, &destination), 40i128);
+    assert_eq!(client.get_user_km_tokens(b, &club_id), 0i128);
+    assert_eq!(client.redeem_usdc(&club_id, d, &destination), 20i128);
+    assert_eq!(client.redeem_usdc(&club_id, c, &destination), 40i128);
+    assert_eq!(client.get_club(&club_id).usdc_deposited, 0i128);
+}
+
+#[test]
+fn test_tc016_threshold_pays_only_qualified_members() {
+    let env = Env::default();
+    let (client, _usdc, club_id, members) = setup(
+        &env,
+        WithdrawalRule::Threshold(5 * KM_UNIT),
+        100,
+        &[6, 5, 4],
+    );
+    let (b, c, d) = (&members[0], &members[1], &members[2]);
+
+    assert_eq!(client.calculate_usdc_reward(&club_id, b), 50i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, c), 50i128);
+    assert_eq!(client.calculate_usdc_reward(&club_id, d), 0i128);
+
+    // D não alcançou a meta: queima os tokens e não recebe nada
+    let destination = Address::generate(&env);
+    assert_eq!(client.redeem_usdc(&club_id, d, &destination), 0i128);
+    assert_eq!(client.redeem_usdc(&club_id, c, &destination), 50i128);
+    assert_eq!(client.redeem_usdc(&club_id, b, &destination), 50i128);
+}
+
+#[test]
+fn test_tc016_invalid_rules_are_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (contract_id, _) = common::register(&env);
+    let client = RunClubContractClient::new(&env, &contract_id);
+
+    let organizer = Address::generate(&env);
+    let tier = |min_km: i128, reward: i128| RewardTier { min_km, reward };
+    let rules = [
+        WithdrawalRule::Tiered(vec![&env]),
+        WithdrawalRule::Tiered(vec![&env, tier(10, 50), tier(5, 30)]),
+        WithdrawalRule::Tiered(vec![&env, tier(5, 0)]),
+        WithdrawalRule::TopN(vec![&env]),
+        WithdrawalRule::TopN(vec![&env, 6_000u32, 5_000]),
+        WithdrawalRule::TopN(vec![&env, 5_000u32, 0]),
+        WithdrawalRule::Threshold(0),
+    ];
+    for rule in rules {
+        assert_eq!(
+            client.try_create_club(
+                &organizer,
+                &String::from_str(&env, "Clube Alpha"),
+                &ClubConfig {
+                    usdc_per_km: 1i128,
+                    withdrawal_rule: rule,
+                    surplus_rule: SurplusRule::ProRata,
+                    start_timestamp: 0u64,
+                    registration_close_timestamp: common::days(30),
+                    end_timestamp: common::days(30),
+                    limits: RunLimits::default(),
+                    max_members: None,
+                },
+            ),
+            Err(Ok(RunClubError::InvalidWithdrawalRule))
+        );
+    }
+}
@@ -1,5 +1,19 @@
+# Stellar Asset Contract do USDC na testnet
+USDC_TOKEN="${USDC_TOKEN:?USDC_TOKEN must be set}"
+
+KM_TOKEN_WASM_HASH=$(stellar contract upload \
+  --wasm target/wasm32v1-none/release/km_token.wasm \
+  --source alice \
+  --network testnet)
+
+# O construtor configura o contrato na mesma transação da implantação
 stellar contract deploy \
   --wasm target/wasm32v1-none/release/run_club.wasm \
   --source alice \
   --network testnet \
-  --alias run_club
+  --alias run_club \
+  -- \
+  --admin alice \
+  --usdc_token $USDC_TOKEN \
+  --km_token_wasm_hash $KM_TOKEN_WASM_HASH \
+  --platform_fee_bps 0
@@ -11,18 +11,21 @@ echo "🏃 Testing Run Club Contract"
 echo "Contract ID: $CONTRACT_ID"
 echo ""
 
-# Initialize the contract
-echo "1. Initializing contract..."
+# The contract is configured by its constructor when deploy.sh deploys it
+echo "1. Checking contract configuration..."
 stellar contract invoke \
   --id $CONTRACT_ID \
   --source $SOURCE \
   --network $NETWORK \
   -- \
-  initialize
+  get_admin
 
 echo ""
 echo "2. Creating a test club..."
 # Create a club (you'll need to replace ORGANIZER_ADDRESS with actual address)
+START=$(date +%s)
+REGISTRATION_CLOSE=$((START + 7 * 24 * 60 * 60))
+END=$((START + 30 * 24 * 60 * 60))
 stellar contract invoke \
   --id $CONTRACT_ID \
   --source $SOURCE \
@@ -31,9 +34,7 @@ stellar contract invoke \
   create_club \
   --organizer GDAT5HWTGIU4TSSZ4752OUC4SABDLTLZFRPZUJ3D6LKBNEPA7V2CIG54 \
   --name "Test Running Club" \
-  --usdc_per_km 100 \
-  --withdrawal_rule Equal \
-  --duration_days 30
+  --config '{"usdc_per_km":"100","withdrawal_rule":"Equal","surplus_rule":"ReturnToOrganizer","start_timestamp":'$START',"registration_close_timestamp":'$REGISTRATION_CLOSE',"end_timestamp":'$END',"limits":{"min_pace_secs_per_km":180,"max_pace_secs_per_km":900,"max_distance_meters":50000,"max_daily_meters":60000,"min_duration_secs":300},"max_members":50}'
 
 echo ""
 echo "3. Getting club info..."